@@ -5,17 +5,23 @@
  *   vargos                first-run → onboard wizard, else → help
  *   vargos start          boot the gateway + all services
  *   vargos onboard        interactive setup (provider, model, API key, channels)
- *   vargos config         print current configuration
+ *   vargos config         show or edit configuration (get/set/unset/edit/path)
+ *   vargos version        print version (--verbose for build + environment info)
+ *   vargos ping           health check — gateway latency, agent service, default model
+ *   vargos completions    print a shell completion script
  *   vargos --version      print version
  *   vargos --help         print usage
  */
 
-import { existsSync, readFileSync } from 'node:fs';
+import { closeSync, existsSync, fstatSync, openSync, readFileSync } from 'node:fs';
 import path from 'node:path';
 import { fileURLToPath } from 'node:url';
 import { CHANNEL_TYPES } from './services/config/schemas/channels.js';
 import type { ChannelEntry } from './services/config/schemas/channels.js';
 import { getDataPaths } from './lib/paths.js';
+import { EXIT, exitCodeFor, type ExitCode } from './cli/exit-codes.js';
+import { parseLogLevel } from './lib/logger.js';
+import { packageVersion } from './lib/version.js';
 
 // ── Runtime guard ────────────────────────────────────────────────────────────
 
@@ -34,10 +40,7 @@ if (v[0] < MIN_NODE[0] || (v[0] === MIN_NODE[0] && v[1] < MIN_NODE[1])) {
 
 const __filename = fileURLToPath(import.meta.url);
 const __dirname = path.dirname(__filename);
-const pkgPath = existsSync(path.join(__dirname, 'package.json'))
-  ? path.join(__dirname, 'package.json')
-  : path.join(__dirname, '..', 'package.json');
-const VERSION = JSON.parse(readFileSync(pkgPath, 'utf8')).version;
+const VERSION = packageVersion();
 
 function usage(): void {
   console.log(`
@@ -46,10 +49,15 @@ function usage(): void {
   Usage:
     vargos                 First-run setup or show this help
     vargos start           Boot the agent server (gateway + all services)
+                           [-v | --log-level <debug|info|warn|error>] [--log-file <path>]
     vargos onboard         Interactive setup wizard (provider, model, API key, channels)
-    vargos config          Show current configuration
+    vargos config          Show or edit configuration (show, path, get, set, unset, edit)
     vargos channels        Manage messaging channels (list, register, deregister, send)
+    vargos sessions        Inspect stored agent sessions (list, show, step, annotate, delete)
     vargos chat            Start an interactive chat session with the agent
+    vargos version         Show version (--verbose for build, environment, and gateway info)
+    vargos ping            Check the running gateway, agent, and default model [--timeout <ms>]
+    vargos completions     Print a shell completion script (bash, zsh, fish)
 
   Options:
     --version, -v          Show version
@@ -69,22 +77,74 @@ function channelsUsage(): void {
     vargos channels register whatsapp <id>               Add a WhatsApp channel, then pair via QR
                                                          (re-run to re-pair an existing channel)
     vargos channels deregister <id>                      Remove a channel
-    vargos channels send <sessionKey> <text...>          Send a message via the running gateway
+    vargos channels send <sessionKey> [text...] [--edit] Send a message via the running gateway
+                                                         (piped stdin, or - to force reading it,
+                                                         is appended to the text;
+                                                         --edit composes it in $VISUAL / $EDITOR)
 
   Examples:
     vargos channels register telegram my-bot --bot-token 123:ABC
     vargos channels register whatsapp my-wa
     vargos channels send telegram-my-bot:-5004637094 "Deploy finished ✅"
+    git log -3 --oneline | vargos channels send telegram-my-bot:-5004637094 "Latest commits:"
+    vargos channels send telegram-my-bot:-5004637094 --edit
+`);
+}
+
+function configUsage(): void {
+  console.log(`
+  Show or change config.json.
+
+  Usage:
+    vargos config                                        Show all config files (default)
+    vargos config show                                   Same as above
+    vargos config path                                   Print the config.json path
+    vargos config get <key>                              Print the effective value (dotted key)
+    vargos config set <key> <value>                      Set a value — numbers, booleans, null and JSON are typed
+    vargos config unset <key>                            Remove a value
+    vargos config edit                                   Open config.json in $VISUAL / $EDITOR, then validate
+
+  Examples:
+    vargos config get gateway.port
+    vargos config set gateway.port 9100
+    vargos config set linkExpand '{"enabled": false}'
+    vargos config set channels.0.enabled false
+`);
+}
+
+function sessionsUsage(): void {
+  console.log(`
+  Inspect stored agent sessions.
+
+  Usage:
+    vargos sessions                                      List sessions (default)
+    vargos sessions list                                 List sessions, most recent first
+    vargos sessions show <sessionKey> [--file <name>]    Print the message history
+    vargos sessions delete <sessionKey> [--yes]          Delete a session's files
+    vargos sessions step <sessionKey> [--file <name>]    Walk a session file entry by entry
+                                                         (latest file unless --file is given)
+    vargos sessions annotate <sessionKey> <step> <note...> [--file <name>]
+                                                         Attach a local-only note to a step
+
+  Keys while stepping: Enter/n next · p previous · q quit.
+  When stdout is not a terminal, all entries are printed in order.
+
+  Examples:
+    vargos sessions step telegram-personal:7789463749
+    vargos sessions step cron:heartbeat:2026-05-06
+    vargos sessions annotate cli 4 "picked the wrong tool here"
 `);
 }
 
 function showConfig(): void {
-  const { configFile, dataDir } = getDataPaths();
+  const { configFile, dataDir, systemConfigFile, policyFile } = getDataPaths();
   const agentDir = `${dataDir}/agent`;
 
   console.log(`Configuration directory: ${dataDir}\n`);
 
   const files: Array<{ label: string; path: string }> = [
+    { label: 'Admin policy', path: policyFile },
+    { label: 'System config', path: systemConfigFile },
     { label: 'App config', path: configFile },
     { label: 'Agent models', path: `${agentDir}/models.json` },
     { label: 'Agent auth', path: `${agentDir}/auth.json` },
@@ -115,6 +175,39 @@ function isFirstRun(): boolean {
   return !existsSync(configFile);
 }
 
+/**
+ * True when stdin is a pipe or redirected file. Sockets, terminals and /dev/null are
+ * left alone — an idle inherited socket (Node spawn, ssh without -n) would block forever.
+ */
+function stdinIsPiped(): boolean {
+  try {
+    const stat = fstatSync(0);
+    return stat.isFIFO() || stat.isFile();
+  } catch {
+    return false;
+  }
+}
+
+/** Open a file in $VISUAL / $EDITOR (vi fallback) and wait for it to exit. */
+async function openInEditor(file: string): Promise<{ editor: string; status: number | null }> {
+  const editor = process.env.VISUAL || process.env.EDITOR || 'vi';
+  const { spawnSync } = await import('node:child_process');
+  // When stdin is a pipe, give the editor the terminal instead
+  let stdin: 'inherit' | number = 'inherit';
+  if (!process.stdin.isTTY) {
+    try {
+      stdin = openSync('/dev/tty', 'r');
+    } catch {
+      // No controlling terminal — let the editor fail on its own
+    }
+  }
+  // $EDITOR may carry flags (`code --wait`), so run it through sh — with the path as "$1",
+  // never interpolated, so quotes, `$` or backticks in it stay literal
+  const res = spawnSync('sh', ['-c', `${editor} "$1"`, 'sh', file], { stdio: [stdin, 'inherit', 'inherit'] });
+  if (typeof stdin === 'number') closeSync(stdin);
+  return { editor, status: res.status };
+}
+
 // ── Arg dispatch ─────────────────────────────────────────────────────────────
 
 const cmd = process.argv[2];
@@ -125,6 +218,107 @@ if (cmd === '--version' || cmd === '-v') {
   process.exit(0);
 }
 
+// version subcommand — `--verbose` adds everything a support request needs
+if (cmd === 'version') {
+  if (!process.argv.includes('--verbose')) {
+    console.log(VERSION);
+    process.exit(0);
+  }
+
+  const { getBuildInfo } = await import('./lib/build-info.js');
+  const { gatewayAddress, gatewayCall } = await import('./cli/gateway.js');
+  const { mergeLayers, readConfigLayers } = await import('./services/config/layers.js');
+
+  // Features reflect the effective config; a broken layer is reported rather than fatal
+  let merged: Record<string, unknown> | undefined;
+  let configError: string | undefined;
+  try {
+    merged = mergeLayers(readConfigLayers());
+  } catch (err) {
+    configError = err instanceof Error ? err.message : String(err);
+  }
+  const info = getBuildInfo(__dirname, merged);
+  const { host, port } = gatewayAddress();
+
+  let gateway: string;
+  try {
+    const { version, services } = await gatewayCall<{ version?: string; services: unknown[] }>('bus.status', {}, 2_000);
+    const mismatch = version !== info.version ? ` ⚠ differs from this CLI (${info.version}) — restart the gateway` : '';
+    gateway = `v${version ?? 'unknown'} running at ${host}:${port} (${services.length} services)${mismatch}`;
+  } catch {
+    gateway = `not reachable at ${host}:${port}`;
+  }
+
+  const fileState = (file: string) => `${file}${existsSync(file) ? '' : ' (missing)'}`;
+
+  console.log(`vargos ${info.version}`);
+  console.log(`  git sha:   ${info.gitSha ?? 'unknown'}`);
+  console.log(`  built:     ${info.buildDate ?? 'unknown (source checkout)'}`);
+  console.log(`  node:      ${info.node}`);
+  console.log(`  os:        ${info.os}`);
+  console.log(`  data dir:  ${info.dataDir}`);
+  console.log(`  config:    ${fileState(info.configFile)}`);
+  console.log(`  system:    ${fileState(info.systemConfigFile)}`);
+  console.log(`  policy:    ${fileState(info.policyFile)}`);
+  if (configError) console.log(`  ⚠ ${configError.split('\n').join('\n    ')}`);
+  console.log(`  features:  ${info.features.length ? info.features.join(', ') : 'none'}${configError ? ' (config.json only)' : ''}`);
+  console.log(`  gateway:   ${gateway}`);
+  process.exit(0);
+}
+
+// ping subcommand — health check for scripts: exits non-zero if any check fails
+if (cmd === 'ping') {
+  const { gatewayAddress, gatewayCall } = await import('./cli/gateway.js');
+  const { host, port } = gatewayAddress();
+  const timeoutArg = process.argv.indexOf('--timeout');
+  const timeoutMs = timeoutArg > -1 ? Number(process.argv[timeoutArg + 1]) : 5_000;
+  if (!Number.isFinite(timeoutMs) || timeoutMs <= 0) {
+    console.error('Usage: vargos ping [--timeout <ms>]');
+    process.exit(EXIT.USAGE);
+  }
+  let code: ExitCode = EXIT.OK;
+
+  let services: Array<{ name: string }>;
+  const started = Date.now();
+  try {
+    ({ services } = await gatewayCall<{ services: Array<{ name: string }> }>('bus.status', {}, timeoutMs));
+  } catch (err) {
+    console.error(`❌ gateway   ${err instanceof Error ? err.message : err}`);
+    process.exit(exitCodeFor(err));
+  }
+  console.log(`✅ gateway   ${host}:${port} (${Date.now() - started}ms, ${services.length} services)`);
+
+  if (services.some(s => s.name === 'agent')) {
+    console.log('✅ agent     service running');
+  } else {
+    console.error('❌ agent     service not registered');
+    code = EXIT.FAILURE;
+  }
+
+  try {
+    const config = await gatewayCall<{
+      agent?: { defaultProvider?: string; defaultModel?: string };
+      providers?: Record<string, { models?: Array<{ id: string }> }>;
+    }>('config.get', {}, timeoutMs);
+    const { defaultProvider, defaultModel } = config.agent ?? {};
+    const custom = defaultProvider ? config.providers?.[defaultProvider] : undefined;
+    if (!defaultProvider || !defaultModel) {
+      console.error('❌ model     no default model configured — run: vargos onboard');
+      code = EXIT.CONFIG;
+    } else if (custom && !custom.models?.some(m => m.id === defaultModel)) {
+      console.error(`❌ model     ${defaultProvider}:${defaultModel} is not listed under providers.${defaultProvider} in agent/models.json`);
+      code = EXIT.CONFIG;
+    } else {
+      console.log(`✅ model     ${defaultProvider}:${defaultModel}`);
+    }
+  } catch (err) {
+    console.error(`❌ config    ${err instanceof Error ? err.message : err}`);
+    code = exitCodeFor(err);
+  }
+
+  process.exit(code);
+}
+
 // --help / -h
 if (cmd === '--help' || cmd === '-h') {
   usage();
@@ -133,8 +327,73 @@ if (cmd === '--help' || cmd === '-h') {
 
 // config subcommand
 if (cmd === 'config') {
-  showConfig();
-  process.exit(0);
+  const sub = process.argv[3];
+
+  if (!sub || sub === 'show') {
+    showConfig();
+    process.exit(0);
+  }
+
+  if (sub === 'path') {
+    console.log(getDataPaths().configFile);
+    process.exit(0);
+  }
+
+  if (sub === '--help' || sub === '-h') {
+    configUsage();
+    process.exit(0);
+  }
+
+  const { resolveConfigValue, setConfigValue, parseConfigValue, validateConfigFile } = await import('./cli/config.js');
+  const key = process.argv[4];
+
+  try {
+    if (sub === 'get' && key) {
+      const { value, layer, file } = resolveConfigValue(key);
+      if (value === undefined) {
+        console.error(`"${key}" is not set in any config layer.`);
+        process.exit(EXIT.NOT_FOUND);
+      }
+      console.log(typeof value === 'string' ? value : JSON.stringify(value, null, 2));
+      // Source on stderr so `$(vargos config get …)` still captures just the value
+      if (layer !== 'config') console.error(`(from ${layer} at ${file})`);
+      process.exit(0);
+    }
+
+    if (sub === 'set' && key && process.argv.length > 5) {
+      setConfigValue(key, parseConfigValue(process.argv.slice(5).join(' ')));
+      console.log(`✅ ${key} updated. Restart the gateway to apply.`);
+      process.exit(0);
+    }
+
+    if (sub === 'unset' && key) {
+      setConfigValue(key, undefined);
+      console.log(`✅ ${key} removed. Restart the gateway to apply.`);
+      process.exit(0);
+    }
+  } catch (err) {
+    console.error(`❌ ${err instanceof Error ? err.message : err}`);
+    process.exit(exitCodeFor(err));
+  }
+
+  if (sub === 'edit') {
+    const { configFile } = getDataPaths();
+    const { editor, status } = await openInEditor(configFile);
+    if (status !== 0) {
+      console.error(`❌ ${editor} exited with status ${status}`);
+      process.exit(EXIT.FAILURE);
+    }
+    const issues = validateConfigFile();
+    if (issues.length > 0) {
+      console.error(`⚠ ${configFile} has problems — the gateway will refuse to boot until fixed:\n  ${issues.join('\n  ')}`);
+      process.exit(EXIT.CONFIG);
+    }
+    console.log(`✅ ${configFile} is valid. Restart the gateway to apply.`);
+    process.exit(0);
+  }
+
+  configUsage();
+  process.exit(EXIT.USAGE);
 }
 
 // onboard subcommand
@@ -146,6 +405,30 @@ if (cmd === 'onboard') {
 
 // start subcommand
 if (cmd === 'start') {
+  // Logging flags become env vars so the supervised boot child inherits them.
+  // Only the flags are validated — an inherited $LOG_LEVEL is read leniently by the logger.
+  const argv = process.argv.slice(3);
+  const levelIdx = argv.indexOf('--log-level');
+  if (levelIdx !== -1) {
+    const level = parseLogLevel(argv[levelIdx + 1]);
+    if (!level) {
+      console.error(`--log-level needs one of debug, info, warn, error (got "${argv[levelIdx + 1] ?? ''}").`);
+      process.exit(EXIT.USAGE);
+    }
+    process.env.LOG_LEVEL = level;
+  } else if (argv.includes('-v') || argv.includes('--verbose')) {
+    process.env.LOG_LEVEL = 'debug';
+  }
+  const fileIdx = argv.indexOf('--log-file');
+  if (fileIdx !== -1) {
+    const file = argv[fileIdx + 1];
+    if (!file || file.startsWith('-')) {
+      console.error('--log-file needs a path.');
+      process.exit(EXIT.USAGE);
+    }
+    process.env.LOG_FILE = path.resolve(file);
+  }
+
   try {
     // Run the supervisor (index.js), which spawns boot.js as a child.
     // The supervisor keeps the event loop alive while the child runs.
@@ -154,7 +437,7 @@ if (cmd === 'start') {
     await new Promise(() => { });
   } catch (err) {
     console.error('Failed to start Vargos:', err);
-    process.exit(1);
+    process.exit(EXIT.FAILURE);
   }
 }
 
@@ -258,7 +541,7 @@ if (cmd === 'chat') {
 // channels subcommand (`channel` accepted as a singular alias)
 if (cmd === 'channels' || cmd === 'channel') {
   const sub = process.argv[3];
-  const { listChannels, registerChannel, deregisterChannel, pairWhatsApp, sendChannelMessage } =
+  const { listChannels, registerChannel, deregisterChannel, pairWhatsApp, sendChannelMessage, composeMessage } =
     await import('./cli/channels.js');
 
   if (sub === '--help' || sub === '-h') {
@@ -292,7 +575,7 @@ if (cmd === 'channels' || cmd === 'channel') {
 
     if (!type || !id || !(CHANNEL_TYPES as readonly string[]).includes(type)) {
       console.log(`Usage: vargos channels register <${CHANNEL_TYPES.join('|')}> <id> [--bot-token <token>]`);
-      process.exit(1);
+      process.exit(EXIT.USAGE);
     }
 
     try {
@@ -301,7 +584,7 @@ if (cmd === 'channels' || cmd === 'channel') {
 
       if (type === 'telegram' && !tgBotKey) {
         console.log('Telegram requires --bot-token <token>');
-        process.exit(1);
+        process.exit(EXIT.USAGE);
       }
 
       const { created } = registerChannel({ id, type, botToken: tgBotKey });
@@ -314,7 +597,7 @@ if (cmd === 'channels' || cmd === 'channel') {
       }
     } catch (err) {
       console.error(`❌ ${err instanceof Error ? err.message : err}`);
-      process.exit(1);
+      process.exit(exitCodeFor(err));
     }
     process.exit(0);
   }
@@ -323,37 +606,220 @@ if (cmd === 'channels' || cmd === 'channel') {
     const id = process.argv[4];
     if (!id) {
       console.log('Usage: vargos channels deregister <id>');
-      process.exit(1);
+      process.exit(EXIT.USAGE);
     }
     try {
       deregisterChannel(id);
       console.log(`✅ Channel "${id}" removed.`);
     } catch (err) {
       console.error(`❌ ${err instanceof Error ? err.message : err}`);
-      process.exit(1);
+      process.exit(exitCodeFor(err));
     }
     process.exit(0);
   }
 
   if (sub === 'send') {
-    const sessionKey = process.argv[4];
-    const text = process.argv.slice(5).join(' ').trim();
-    if (!sessionKey || !text) {
-      console.log('Usage: vargos channels send <sessionKey> <text...>');
-      process.exit(1);
+    const edit = process.argv.includes('--edit');
+    const [sessionKey, ...args] = process.argv.slice(4).filter(a => a !== '--edit');
+    // Piped stdin is appended to the text argument: `git log -3 | vargos channels send <key> "Latest commits:"`.
+    // A lone `-` forces reading stdin when it isn't a pipe or file (e.g. a socket).
+    const words = args.filter(a => a !== '-');
+    let piped = '';
+    if (words.length < args.length || stdinIsPiped()) {
+      // Decode as one stream so a multi-byte character split across chunks stays intact
+      process.stdin.setEncoding('utf8');
+      for await (const chunk of process.stdin) piped += chunk;
+    }
+    let text = composeMessage(words.join(' '), piped);
+    if (!sessionKey || (!text && !edit)) {
+      console.log('Usage: vargos channels send <sessionKey> [text...] [-] [--edit]   (piped stdin, or - to force it, is appended)');
+      process.exit(EXIT.USAGE);
+    }
+
+    // --edit: compose in $EDITOR, starting from whatever was given on the command line
+    if (edit) {
+      const { mkdtempSync, writeFileSync, rmSync } = await import('node:fs');
+      const os = await import('node:os');
+      const dir = mkdtempSync(path.join(os.tmpdir(), 'vargos-send-'));
+      const draft = path.join(dir, 'message.md');
+      writeFileSync(draft, text ? `${text}\n` : '');
+      const { editor, status } = await openInEditor(draft);
+      const edited = readFileSync(draft, 'utf8');
+      rmSync(dir, { recursive: true, force: true });
+      if (status !== 0) {
+        console.error(`❌ ${editor} exited with status ${status} — nothing sent.`);
+        process.exit(EXIT.FAILURE);
+      }
+      text = composeMessage(edited);
+      if (!text) {
+        console.log('Cancelled — empty message, nothing sent.');
+        process.exit(0);
+      }
     }
     try {
       const sent = await sendChannelMessage(sessionKey, text);
       console.log(sent ? `✅ Sent to ${sessionKey}` : `⚠ Not delivered — no active channel for "${sessionKey}".`);
-      process.exit(sent ? 0 : 1);
+      process.exit(sent ? EXIT.OK : EXIT.FAILURE);
     } catch (err) {
       console.error(`❌ ${err instanceof Error ? err.message : err}`);
-      process.exit(1);
+      process.exit(exitCodeFor(err));
     }
   }
 
   channelsUsage();
-  process.exit(1);
+  process.exit(EXIT.USAGE);
+}
+
+// sessions subcommand (`session` accepted as a singular alias)
+if (cmd === 'sessions' || cmd === 'session') {
+  const sub = process.argv[3];
+  const {
+    listSessions, deleteSession, listSessionFiles, readSessionEntries, describeEntry, formatHistory,
+    sessionDir, readAnnotations, addAnnotation,
+  } = await import('./cli/sessions.js');
+
+  // Positional args with `--file <name>` pulled out
+  const fileIdx = process.argv.indexOf('--file');
+  const fileName = fileIdx !== -1 ? process.argv[fileIdx + 1] : undefined;
+  const args = process.argv.slice(4).filter((_, i) => fileIdx === -1 || ![fileIdx, fileIdx + 1].includes(i + 4));
+
+  const resolveFile = (sessionKey: string) => {
+    const files = listSessionFiles(sessionKey);
+    const file = fileName ? files.find(f => f.name === fileName) : files[0];
+    if (!file) {
+      console.error(`❌ No session file found in ${sessionDir(sessionKey)}`);
+      process.exit(EXIT.NOT_FOUND);
+    }
+    return file;
+  };
+
+  if (sub === 'list' || !sub) {
+    const sessions = listSessions();
+    if (sessions.length === 0) {
+      console.log('No sessions stored yet.');
+    } else {
+      console.log('Sessions:\n');
+      for (const s of sessions) {
+        console.log(`  ${s.sessionKey}  ${s.lastActive.toISOString()}  (${s.files} file${s.files === 1 ? '' : 's'})`);
+      }
+      console.log('');
+    }
+    process.exit(0);
+  }
+
+  if (sub === 'show') {
+    const sessionKey = args[0];
+    if (!sessionKey) {
+      console.log('Usage: vargos sessions show <sessionKey> [--file <name>]');
+      process.exit(EXIT.USAGE);
+    }
+    const file = resolveFile(sessionKey);
+    console.log(`${file.path}\n`);
+    console.log(formatHistory(readSessionEntries(file.path), readAnnotations(file.path)));
+    process.exit(0);
+  }
+
+  if (sub === 'delete') {
+    const sessionKey = args[0];
+    if (!sessionKey) {
+      console.log('Usage: vargos sessions delete <sessionKey> [--yes]');
+      process.exit(EXIT.USAGE);
+    }
+    if (!process.argv.includes('--yes')) {
+      const p = await import('@clack/prompts');
+      const ok = await p.confirm({ message: `Delete all session files for "${sessionKey}"?`, initialValue: false });
+      if (p.isCancel(ok) || !ok) { p.cancel('Cancelled — nothing deleted.'); process.exit(0); }
+    }
+    try {
+      const n = deleteSession(sessionKey);
+      console.log(`✅ Deleted ${n} file(s) for "${sessionKey}".`);
+      console.log('   A running gateway keeps the session in memory until restart.');
+    } catch (err) {
+      console.error(`❌ ${err instanceof Error ? err.message : err}`);
+      process.exit(exitCodeFor(err));
+    }
+    process.exit(0);
+  }
+
+  if (sub === 'step') {
+    const sessionKey = args[0];
+    if (!sessionKey) {
+      console.log('Usage: vargos sessions step <sessionKey> [--file <name>]');
+      process.exit(EXIT.USAGE);
+    }
+
+    const file = resolveFile(sessionKey);
+    const entries = readSessionEntries(file.path);
+    const notes = readAnnotations(file.path);
+    const show = (i: number) => {
+      console.log(`\n── step ${i + 1}/${entries.length} · ${describeEntry(entries[i])} ──`);
+      console.log(JSON.stringify(entries[i], null, 2));
+      for (const note of notes[String(i + 1)] ?? []) console.log(`  📝 ${note}`);
+    };
+
+    console.log(`${file.path} (${entries.length} entries)`);
+    if (!process.stdin.isTTY || !process.stdout.isTTY) {
+      entries.forEach((_, i) => show(i));
+      process.exit(0);
+    }
+
+    const { createInterface } = await import('node:readline/promises');
+    const rl = createInterface({ input: process.stdin, output: process.stdout });
+    let i = 0;
+    while (i < entries.length) {
+      show(i);
+      const key = (await rl.question('[Enter/n] next  [p] previous  [q] quit › ')).trim().toLowerCase();
+      if (key === 'q') break;
+      i = key === 'p' ? Math.max(0, i - 1) : i + 1;
+    }
+    rl.close();
+    process.exit(0);
+  }
+
+  if (sub === 'annotate') {
+    const [sessionKey, step, ...words] = args;
+    const note = words.join(' ').trim();
+    if (!sessionKey || !step || !note) {
+      console.log('Usage: vargos sessions annotate <sessionKey> <step> <note...> [--file <name>]');
+      process.exit(EXIT.USAGE);
+    }
+    try {
+      addAnnotation(resolveFile(sessionKey).path, Number(step), note);
+      console.log(`✅ Noted step ${step} of ${sessionKey}`);
+    } catch (err) {
+      console.error(`❌ ${err instanceof Error ? err.message : err}`);
+      process.exit(exitCodeFor(err));
+    }
+    process.exit(0);
+  }
+
+  sessionsUsage();
+  process.exit(sub === '--help' || sub === '-h' ? EXIT.OK : EXIT.USAGE);
+}
+
+// completions subcommand — `--list <kind>` is the hook the scripts call for dynamic values
+if (cmd === 'completions') {
+  const { SHELLS, completionScript, dynamicValues } = await import('./cli/completions.js');
+  const arg = process.argv[3];
+
+  if (arg === '--list') {
+    const kind = process.argv[4];
+    if (kind === 'channels' || kind === 'sessions') {
+      for (const value of dynamicValues(kind)) console.log(value);
+    }
+    process.exit(0);
+  }
+
+  if (!arg || !(SHELLS as readonly string[]).includes(arg)) {
+    console.log(`Usage: vargos completions <${SHELLS.join('|')}>\n`);
+    console.log('  bash:  eval "$(vargos completions bash)"              # in ~/.bashrc');
+    console.log('  zsh:   eval "$(vargos completions zsh)"               # in ~/.zshrc, after compinit');
+    console.log('  fish:  vargos completions fish > ~/.config/fish/completions/vargos.fish');
+    process.exit(arg ? EXIT.USAGE : EXIT.OK);
+  }
+
+  process.stdout.write(completionScript(arg as typeof SHELLS[number]));
+  process.exit(0);
 }
 
 // No command — first-run or help
@@ -5,7 +5,8 @@ import { createServer, type Server } from 'node:net';
 import * as path from 'node:path';
 import * as os from 'node:os';
 import { resetDataPaths } from '../../lib/paths.js';
-import { registerChannel, listChannels, deregisterChannel, sendChannelMessage } from '../channels.js';
+import { registerChannel, listChannels, deregisterChannel, sendChannelMessage, composeMessage } from '../channels.js';
+import { EXIT } from '../exit-codes.js';
 
 function writeConfig(dataDir: string, config: Record<string, unknown>) {
   writeFileSync(path.join(dataDir, 'config.json'), JSON.stringify(config, null, 2));
@@ -81,6 +82,18 @@ describe('registerChannel (idempotent upsert)', () => {
   });
 });
 
+describe('composeMessage', () => {
+  it('appends piped content after the text argument', () => {
+    expect(composeMessage('summary:', 'line 1\nline 2\n')).toBe('summary:\n\nline 1\nline 2');
+  });
+
+  it('uses whichever part is present', () => {
+    expect(composeMessage('', 'piped only\n')).toBe('piped only');
+    expect(composeMessage(' text only ')).toBe('text only');
+    expect(composeMessage('  ', '\n')).toBe('');
+  });
+});
+
 describe('sendChannelMessage (gateway client)', () => {
   let tmpDir: string;
   let originalEnv: string | undefined;
@@ -131,5 +144,6 @@ describe('sendChannelMessage (gateway client)', () => {
   it('rejects with a helpful message when the gateway is unreachable', async () => {
     writeConfig(tmpDir, { gateway: { host: '127.0.0.1', port: 59999 } });
     await expect(sendChannelMessage('x:1', 'hi')).rejects.toThrow(/not reachable/);
+    await expect(sendChannelMessage('x:1', 'hi')).rejects.toMatchObject({ exitCode: EXIT.GATEWAY });
   });
 });
@@ -0,0 +1,42 @@
+import { describe, it, expect } from 'vitest';
+import { spawnSync } from 'node:child_process';
+import { COMMANDS, SHELLS, completionScript } from '../completions.js';
+
+describe('completionScript', () => {
+  it.each(SHELLS)('%s script offers every top-level command', (shell) => {
+    const script = completionScript(shell);
+    for (const name of Object.keys(COMMANDS)) expect(script).toContain(name);
+  });
+
+  it.each(SHELLS)('%s script looks up channel ids and session keys dynamically', (shell) => {
+    const script = completionScript(shell);
+    expect(script).toContain('vargos completions --list channels');
+    expect(script).toContain('vargos completions --list sessions');
+  });
+
+  it('completes aliases like their canonical command', () => {
+    const bash = completionScript('bash');
+    expect(bash).toContain('channels|channel) words="list register deregister send --help"');
+    expect(bash).toContain('"channels register"|"channel register") words="telegram whatsapp"');
+    // send takes a sessionKey, not a channel id
+    expect(bash).toContain('"channels send"|"channel send") words="$(vargos completions --list sessions 2>/dev/null)"');
+  });
+
+  it('bash completes session keys containing ":" despite COMP_WORDBREAKS', () => {
+    const complete = (line: string) => spawnSync('bash', ['-c', [
+      completionScript('bash'),
+      'vargos() { echo "telegram-a:42 telegram-a:43 cli"; }',
+      `COMP_LINE='${line}'; COMP_POINT=\${#COMP_LINE}; _vargos; echo "\${COMPREPLY[*]}"`,
+    ].join('\n')], { encoding: 'utf-8' }).stdout.trim();
+
+    expect(complete('vargos sessions show tel')).toBe('telegram-a:42 telegram-a:43');
+    // Bash only replaces the part after the last ':'
+    expect(complete('vargos sessions show telegram-a:4')).toBe('42 43');
+  });
+
+  it('registers the completion function for each shell', () => {
+    expect(completionScript('bash')).toContain('complete -F _vargos vargos');
+    expect(completionScript('zsh')).toContain('compdef _vargos vargos');
+    expect(completionScript('fish')).toContain("complete -c vargos -n '__fish_use_subcommand'");
+  });
+});
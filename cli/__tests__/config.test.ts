@@ -0,0 +1,130 @@
+import { describe, it, expect, beforeEach, afterEach } from 'vitest';
+import { mkdirSync, writeFileSync, readFileSync, rmSync } from 'node:fs';
+import * as path from 'node:path';
+import * as os from 'node:os';
+import { resetDataPaths } from '../../lib/paths.js';
+import { resolveConfigValue, setConfigValue, parseConfigValue, validateConfigFile } from '../config.js';
+import { EXIT, exitCodeFor } from '../exit-codes.js';
+
+describe('config get/set', () => {
+  let tmpDir: string;
+  const saved: Record<string, string | undefined> = {};
+
+  const readConfig = () => JSON.parse(readFileSync(path.join(tmpDir, 'config.json'), 'utf-8'));
+
+  beforeEach(() => {
+    tmpDir = path.join(os.tmpdir(), `cli-config-${process.pid}-${Date.now()}-${Math.random().toString(36).slice(2)}`);
+    mkdirSync(tmpDir, { recursive: true });
+    for (const k of ['VARGOS_DATA_DIR', 'VARGOS_SYSTEM_CONFIG']) saved[k] = process.env[k];
+    process.env.VARGOS_DATA_DIR = tmpDir;
+    process.env.VARGOS_SYSTEM_CONFIG = path.join(tmpDir, 'system.json');
+    resetDataPaths({ policyFile: path.join(tmpDir, 'policy.json') });
+    writeFileSync(path.join(tmpDir, 'config.json'), JSON.stringify({
+      gateway: { port: 9000 },
+      channels: [{ id: 'tg', type: 'telegram', botToken: 'x', enabled: true }],
+    }));
+  });
+
+  afterEach(() => {
+    for (const [k, v] of Object.entries(saved)) {
+      if (v === undefined) delete process.env[k];
+      else process.env[k] = v;
+    }
+    resetDataPaths();
+    rmSync(tmpDir, { recursive: true, force: true });
+  });
+
+  it('parses CLI strings into typed values', () => {
+    expect(parseConfigValue('9100')).toBe(9100);
+    expect(parseConfigValue('false')).toBe(false);
+    expect(parseConfigValue('null')).toBeNull();
+    expect(parseConfigValue('{"enabled": false}')).toEqual({ enabled: false });
+    expect(parseConfigValue('127.0.0.1')).toBe('127.0.0.1');
+    expect(parseConfigValue('{not json')).toBe('{not json');
+  });
+
+  it('reads dotted keys through objects and arrays', () => {
+    expect(resolveConfigValue('gateway.port').value).toBe(9000);
+    expect(resolveConfigValue('channels.0.id').value).toBe('tg');
+    expect(resolveConfigValue('gateway.missing').value).toBeUndefined();
+  });
+
+  it('reports the effective value and the layer it comes from', () => {
+    writeFileSync(path.join(tmpDir, 'system.json'), JSON.stringify({ gateway: { host: '0.0.0.0', port: 8000 } }));
+    writeFileSync(path.join(tmpDir, 'policy.json'), JSON.stringify({ linkExpand: { enabled: false } }));
+
+    expect(resolveConfigValue('gateway.host')).toEqual({
+      value: '0.0.0.0', layer: 'system config', file: path.join(tmpDir, 'system.json'),
+    });
+    expect(resolveConfigValue('gateway.port')).toMatchObject({ value: 9000, layer: 'config' });
+    expect(resolveConfigValue('linkExpand.enabled')).toMatchObject({ value: false, layer: 'policy' });
+    expect(resolveConfigValue('gateway')).toMatchObject({ value: { host: '0.0.0.0', port: 9000 }, layer: 'config' });
+    expect(resolveConfigValue('gateway.missing')).toEqual({ value: undefined });
+  });
+
+  it('reads agent, providers and auth keys from agent/*.json as the gateway does', () => {
+    mkdirSync(path.join(tmpDir, 'agent'));
+    writeFileSync(path.join(tmpDir, 'agent', 'settings.json'), JSON.stringify({ defaultThinkingLevel: 'low' }));
+    writeFileSync(path.join(tmpDir, 'agent', 'models.json'), JSON.stringify({ providers: { openai: { api: 'x' } } }));
+
+    expect(resolveConfigValue('agent.defaultThinkingLevel')).toEqual({
+      value: 'low', layer: 'agent', file: path.join(tmpDir, 'agent', 'settings.json'),
+    });
+    expect(resolveConfigValue('providers.openai.api')).toMatchObject({ value: 'x', layer: 'agent' });
+  });
+
+  it('writes agent keys to agent/settings.json, not config.json', () => {
+    setConfigValue('agent.defaultThinkingLevel', 'high');
+
+    const settings = JSON.parse(readFileSync(path.join(tmpDir, 'agent', 'settings.json'), 'utf-8'));
+    expect(settings).toEqual({ defaultThinkingLevel: 'high' });
+    expect(readConfig()).not.toHaveProperty('agent');
+    expect(resolveConfigValue('agent.defaultThinkingLevel').value).toBe('high');
+
+    expect(() => setConfigValue('agent.defaultThinkingLevel', 'bogus')).toThrow(/Invalid value for "agent.defaultThinkingLevel"/);
+    setConfigValue('agent.defaultThinkingLevel', undefined);
+    expect(JSON.parse(readFileSync(path.join(tmpDir, 'agent', 'settings.json'), 'utf-8'))).toEqual({});
+  });
+
+  it('writes valid values and creates intermediate objects', () => {
+    setConfigValue('gateway.port', 9100);
+    setConfigValue('linkExpand.enabled', false);
+    expect(readConfig().gateway.port).toBe(9100);
+    expect(readConfig().linkExpand).toEqual({ enabled: false });
+  });
+
+  it('rejects values that fail schema validation without writing', () => {
+    expect(() => setConfigValue('gateway.port', 'not-a-port')).toThrow(/Invalid value for "gateway.port"/);
+    expect(readConfig().gateway.port).toBe(9000);
+  });
+
+  it('unsets keys', () => {
+    setConfigValue('gateway.port', undefined);
+    expect(readConfig().gateway).toEqual({});
+  });
+
+  it('refuses keys pinned by admin policy', () => {
+    writeFileSync(path.join(tmpDir, 'policy.json'), JSON.stringify({ gateway: { port: 9000 } }));
+    expect(() => setConfigValue('gateway.port', 9100)).toThrow(/locked by admin policy/);
+  });
+
+  it('maps failures to documented exit codes', () => {
+    const codeOf = (fn: () => unknown) => {
+      try {
+        fn();
+      } catch (err) {
+        return exitCodeFor(err);
+      }
+      return EXIT.OK;
+    };
+    expect(codeOf(() => setConfigValue('gateway.port', 'not-a-port'))).toBe(EXIT.CONFIG);
+    expect(codeOf(() => setConfigValue('gateway..port', 1))).toBe(EXIT.USAGE);
+    expect(codeOf(() => { throw new Error('boom'); })).toBe(EXIT.FAILURE);
+  });
+
+  it('reports schema issues for the file on disk', () => {
+    expect(validateConfigFile()).toEqual([]);
+    writeFileSync(path.join(tmpDir, 'config.json'), '{ broken');
+    expect(validateConfigFile()).toHaveLength(1);
+  });
+});
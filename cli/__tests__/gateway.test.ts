@@ -0,0 +1,49 @@
+import { describe, it, expect, beforeEach, afterEach } from 'vitest';
+import { mkdirSync, writeFileSync, rmSync } from 'node:fs';
+import * as path from 'node:path';
+import * as os from 'node:os';
+import { resetDataPaths } from '../../lib/paths.js';
+import { gatewayAddress } from '../gateway.js';
+
+describe('gatewayAddress', () => {
+  let tmpDir: string;
+  const saved: Record<string, string | undefined> = {};
+  const write = (name: string, data: unknown) => writeFileSync(path.join(tmpDir, name), JSON.stringify(data));
+
+  beforeEach(() => {
+    tmpDir = path.join(os.tmpdir(), `cli-gateway-${process.pid}-${Date.now()}-${Math.random().toString(36).slice(2)}`);
+    mkdirSync(tmpDir, { recursive: true });
+    for (const k of ['VARGOS_DATA_DIR', 'VARGOS_SYSTEM_CONFIG', 'BUS_HOST', 'BUS_PORT']) {
+      saved[k] = process.env[k];
+      delete process.env[k];
+    }
+    process.env.VARGOS_DATA_DIR = tmpDir;
+    process.env.VARGOS_SYSTEM_CONFIG = path.join(tmpDir, 'system.json');
+    resetDataPaths({ policyFile: path.join(tmpDir, 'policy.json') });
+  });
+
+  afterEach(() => {
+    for (const [k, v] of Object.entries(saved)) {
+      if (v === undefined) delete process.env[k];
+      else process.env[k] = v;
+    }
+    resetDataPaths();
+    rmSync(tmpDir, { recursive: true, force: true });
+  });
+
+  it('defaults to 127.0.0.1:9000', () => {
+    expect(gatewayAddress()).toEqual({ host: '127.0.0.1', port: 9000 });
+  });
+
+  it('inherits an admin-provisioned address beneath the user config', () => {
+    write('system.json', { gateway: { host: '10.0.0.5', port: 9300 } });
+    write('config.json', { gateway: { port: 9100 } });
+    expect(gatewayAddress()).toEqual({ host: '10.0.0.5', port: 9100 });
+  });
+
+  it('lets admin policy override the user config', () => {
+    write('config.json', { gateway: { port: 9100 } });
+    write('policy.json', { gateway: { port: 9400 } });
+    expect(gatewayAddress().port).toBe(9400);
+  });
+});
@@ -0,0 +1,133 @@
+import { describe, it, expect, beforeEach, afterEach } from 'vitest';
+import { mkdirSync, writeFileSync, rmSync, utimesSync, existsSync } from 'node:fs';
+import * as path from 'node:path';
+import * as os from 'node:os';
+import { resetDataPaths } from '../../lib/paths.js';
+import {
+  sessionDir, listSessions, deleteSession, listSessionFiles, readSessionEntries, describeEntry, entryText,
+  addAnnotation, readAnnotations, formatHistory,
+} from '../sessions.js';
+
+describe('session inspection', () => {
+  let tmpDir: string;
+  let originalEnv: string | undefined;
+
+  beforeEach(() => {
+    tmpDir = path.join(os.tmpdir(), `cli-sessions-${process.pid}-${Date.now()}-${Math.random().toString(36).slice(2)}`);
+    mkdirSync(tmpDir, { recursive: true });
+    originalEnv = process.env.VARGOS_DATA_DIR;
+    process.env.VARGOS_DATA_DIR = tmpDir;
+    resetDataPaths();
+  });
+
+  afterEach(() => {
+    if (originalEnv === undefined) delete process.env.VARGOS_DATA_DIR;
+    else process.env.VARGOS_DATA_DIR = originalEnv;
+    resetDataPaths();
+    rmSync(tmpDir, { recursive: true, force: true });
+  });
+
+  it('maps sessionKey segments to directories', () => {
+    expect(sessionDir('cron:heartbeat:2026-05-06'))
+      .toBe(path.join(tmpDir, 'sessions', 'cron', 'heartbeat', '2026-05-06'));
+  });
+
+  it('rejects keys that escape the sessions dir', () => {
+    mkdirSync(path.join(tmpDir, 'logs'), { recursive: true });
+    writeFileSync(path.join(tmpDir, 'logs', 'x.jsonl'), '{}\n');
+
+    for (const key of ['../logs', '..:logs', 'cli:..:..:logs', '']) {
+      expect(() => deleteSession(key)).toThrow(/Invalid session key/);
+    }
+    expect(existsSync(path.join(tmpDir, 'logs', 'x.jsonl'))).toBe(true);
+  });
+
+  it('lists JSONL files newest first and ignores other files', () => {
+    const dir = sessionDir('telegram-a:42');
+    mkdirSync(dir, { recursive: true });
+    writeFileSync(path.join(dir, 'old.jsonl'), '{}\n');
+    writeFileSync(path.join(dir, 'new.jsonl'), '{}\n');
+    writeFileSync(path.join(dir, 'systemPrompt.md'), 'x');
+    utimesSync(path.join(dir, 'old.jsonl'), new Date(1_000), new Date(1_000));
+
+    expect(listSessionFiles('telegram-a:42').map(f => f.name)).toEqual(['new.jsonl', 'old.jsonl']);
+    expect(listSessionFiles('missing:1')).toEqual([]);
+  });
+
+  it('keeps malformed lines as raw entries', () => {
+    const file = path.join(tmpDir, 's.jsonl');
+    writeFileSync(file, '{"type":"session"}\nnot json\n\n{"type":"message","message":{"role":"user"}}\n');
+
+    const entries = readSessionEntries(file);
+    expect(entries).toHaveLength(3);
+    expect(entries[1]).toEqual({ raw: 'not json' });
+  });
+
+  it('describes entries by type, role, and timestamp', () => {
+    expect(describeEntry({ type: 'message', message: { role: 'assistant' }, timestamp: '2026-05-06T10:00:00Z' }))
+      .toBe('message/assistant · 2026-05-06T10:00:00Z');
+    expect(describeEntry({ role: 'user', content: 'hi' })).toBe('user');
+    expect(describeEntry({ raw: 'garbage' })).toBe('unparsed line');
+    expect(describeEntry({})).toBe('entry');
+  });
+
+  it('stores annotations in a sidecar keyed by step', () => {
+    const dir = sessionDir('cli');
+    mkdirSync(dir, { recursive: true });
+    const file = path.join(dir, 's.jsonl');
+    writeFileSync(file, '{"type":"session"}\n{"role":"user"}\n');
+
+    addAnnotation(file, 2, 'asked twice');
+    addAnnotation(file, 2, 'see step 1');
+
+    expect(readAnnotations(file)).toEqual({ 2: ['asked twice', 'see step 1'] });
+    expect(listSessionFiles('cli').map(f => f.name)).toEqual(['s.jsonl']);
+    expect(() => addAnnotation(file, 3, 'nope')).toThrow(/out of range/);
+  });
+
+  it('lists every directory holding session files as a sessionKey', () => {
+    for (const key of ['cli', 'telegram-a:42', 'telegram-a:42:subagent:r1']) {
+      mkdirSync(sessionDir(key), { recursive: true });
+      writeFileSync(path.join(sessionDir(key), 's.jsonl'), '{}\n');
+    }
+    mkdirSync(sessionDir('empty:dir'), { recursive: true });
+
+    expect(listSessions().map(s => s.sessionKey).sort())
+      .toEqual(['cli', 'telegram-a:42', 'telegram-a:42:subagent:r1']);
+  });
+
+  it('deletes only the session\'s own files, keeping nested sessions', () => {
+    const parent = sessionDir('telegram-a:42');
+    const child = sessionDir('telegram-a:42:subagent:r1');
+    mkdirSync(child, { recursive: true });
+    writeFileSync(path.join(parent, 's.jsonl'), '{}\n');
+    writeFileSync(path.join(child, 's.jsonl'), '{}\n');
+
+    expect(deleteSession('telegram-a:42')).toBe(1);
+    expect(existsSync(path.join(parent, 's.jsonl'))).toBe(false);
+    expect(existsSync(path.join(child, 's.jsonl'))).toBe(true);
+    expect(() => deleteSession('telegram-a:42')).toThrow(/not found/);
+  });
+
+  it('extracts message text from Pi entries and flat entries', () => {
+    expect(entryText({ type: 'message', message: { role: 'assistant', content: [
+      { type: 'text', text: 'hello' }, { type: 'toolCall', name: 'bash' }, { type: 'text', text: 'world' },
+    ] } })).toEqual({ role: 'assistant', text: 'hello\nworld' });
+    expect(entryText({ role: 'user', content: 'hi' })).toEqual({ role: 'user', text: 'hi' });
+    expect(entryText({ type: 'session' })).toBeUndefined();
+  });
+
+  it('shows each entry\'s notes under it in the history', () => {
+    const entries = [
+      { type: 'session' },
+      { role: 'user', content: 'hi' },
+      { type: 'model_change' },
+      { role: 'assistant', content: 'hello' },
+    ];
+    expect(formatHistory(entries, { 2: ['asked politely'], 3: ['switched model here'] })).toBe([
+      '[user] hi\n  📝 asked politely',
+      '[step 3 · model_change]\n  📝 switched model here',
+      '[assistant] hello',
+    ].join('\n\n'));
+  });
+});
@@ -7,12 +7,14 @@
  *   deregisterChannel()   → remove from config.json
  *   pairWhatsApp()        → standalone QR pairing (stops after connected)
  *   sendChannelMessage()  → deliver a message via the running gateway (channel.send)
+ *   composeMessage()      → join the text argument with piped stdin (`cmd | vargos channels send …`)
  */
 
 import { existsSync, mkdirSync, readFileSync, writeFileSync } from 'node:fs';
-import { createConnection } from 'node:net';
 import path from 'node:path';
 import { getDataPaths } from '../lib/paths.js';
+import { gatewayCall } from './gateway.js';
+import { CliError, EXIT } from './exit-codes.js';
 import type { ChannelEntry } from '../services/config/schemas/channels.js';
 
 // ── Types ─────────────────────────────────────────────────────────────────────
@@ -114,7 +116,7 @@ export function deregisterChannel(id: string): void {
   const config = readConfig();
   const channels = (config.channels ?? []) as Array<Record<string, unknown>>;
   const idx = channels.findIndex((c) => c.id === id);
-  if (idx === -1) throw new Error(`Channel "${id}" not found.`);
+  if (idx === -1) throw new CliError(`Channel "${id}" not found.`, EXIT.NOT_FOUND);
   channels.splice(idx, 1);
   config.channels = channels;
   writeConfig(config);
@@ -161,47 +163,11 @@ export async function pairWhatsApp(id: string): Promise<void> {
   });
 }
 
-// ── Gateway client (talks to a running `vargos start`) ─────────────────────────
+// ── Gateway delivery ─────────────────────────────────────────────────────────
 
-/** Resolve the gateway address from config.json, mirroring boot.ts defaults. */
-function gatewayAddress(): { host: string; port: number } {
-  const gw = (readConfig().gateway ?? {}) as { host?: string; port?: number };
-  const host = gw.host ?? process.env.BUS_HOST ?? '127.0.0.1';
-  const port = gw.port ?? (process.env.BUS_PORT ? parseInt(process.env.BUS_PORT, 10) : 9000);
-  return { host, port };
-}
-
-/** Send one JSON-RPC request to the gateway and resolve with its result. */
-function gatewayCall<T>(method: string, params: unknown): Promise<T> {
-  const { host, port } = gatewayAddress();
-  return new Promise<T>((resolve, reject) => {
-    const socket = createConnection({ host, port }, () => {
-      socket.write(JSON.stringify({ jsonrpc: '2.0', method, params, id: 1 }) + '\n');
-    });
-
-    let buffer = '';
-    socket.on('data', (chunk) => {
-      buffer += chunk.toString();
-      const nl = buffer.indexOf('\n');
-      if (nl === -1) return; // wait for the full line
-      socket.end();
-      try {
-        const res = JSON.parse(buffer.slice(0, nl)) as { result?: T; error?: { message?: string } };
-        if (res.error) reject(new Error(res.error.message ?? 'gateway error'));
-        else resolve(res.result as T);
-      } catch (err) {
-        reject(err instanceof Error ? err : new Error(String(err)));
-      }
-    });
-
-    socket.setTimeout(10_000, () => {
-      socket.destroy();
-      reject(new Error(`gateway not reachable at ${host}:${port} — is "vargos start" running?`));
-    });
-    socket.on('error', (err) => reject(
-      new Error(`gateway not reachable at ${host}:${port} — is "vargos start" running? (${err.message})`),
-    ));
-  });
+/** Text argument first, piped content after it — either may be empty. */
+export function composeMessage(text: string, piped = ''): string {
+  return [text.trim(), piped.replace(/\s+$/, '')].filter(Boolean).join('\n\n');
 }
 
 /** Deliver a message to a channel session via the running gateway. */
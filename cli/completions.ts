@@ -0,0 +1,164 @@
+/**
+ * Shell completion scripts for the vargos CLI.
+ *
+ * Exports:
+ *   SHELLS              → supported shells
+ *   completionScript()  → bash / zsh / fish script generated from COMMANDS
+ *   dynamicValues()     → channel ids / session keys, printed by `vargos completions --list <kind>`
+ *
+ * Keep COMMANDS in sync with the dispatch in cli.ts.
+ */
+
+import { listChannels } from './channels.js';
+import { listSessions } from './sessions.js';
+
+// ── Spec ──────────────────────────────────────────────────────────────────────
+
+export const SHELLS = ['bash', 'zsh', 'fish'] as const;
+export type Shell = typeof SHELLS[number];
+
+export type DynamicKind = 'channels' | 'sessions';
+
+interface CommandSpec {
+  aliases?: string[];
+  /** Words completed right after the command (subcommands and flags) */
+  words: string[];
+  /** Words completed after a given subcommand — static list or looked up at completion time */
+  args?: Record<string, string[] | DynamicKind>;
+}
+
+export const COMMANDS: Record<string, CommandSpec> = {
+  start: { words: ['--verbose', '--log-level', '--log-file'], args: { '--log-level': ['debug', 'info', 'warn', 'error'] } },
+  onboard: { words: [] },
+  config: { words: ['show', 'path', 'get', 'set', 'unset', 'edit', '--help'] },
+  version: { words: ['--verbose'] },
+  ping: { words: ['--timeout'] },
+  migrate: { words: ['--dry-run'] },
+  sync: { words: [] },
+  chat: { words: [] },
+  channels: {
+    aliases: ['channel'],
+    words: ['list', 'register', 'deregister', 'send', '--help'],
+    args: { register: ['telegram', 'whatsapp'], deregister: 'channels', send: 'sessions' },
+  },
+  sessions: {
+    aliases: ['session'],
+    words: ['list', 'show', 'step', 'annotate', 'delete', '--help'],
+    args: { show: 'sessions', step: 'sessions', annotate: 'sessions', delete: 'sessions' },
+  },
+  completions: { words: [...SHELLS] },
+};
+
+const TOP_LEVEL = [...Object.keys(COMMANDS), '--help', '--version'];
+
+/** Values for dynamic completion — read straight from disk, no gateway needed. */
+export function dynamicValues(kind: DynamicKind): string[] {
+  return kind === 'channels'
+    ? listChannels().map(c => c.id)
+    : listSessions().map(s => s.sessionKey);
+}
+
+// ── Generators ────────────────────────────────────────────────────────────────
+
+const names = (name: string, spec: CommandSpec) => [name, ...(spec.aliases ?? [])];
+const lookup = (kind: DynamicKind) => `vargos completions --list ${kind} 2>/dev/null`;
+
+function bash(): string {
+  const level2 = Object.entries(COMMANDS)
+    .filter(([, spec]) => spec.words.length > 0)
+    .map(([name, spec]) => `      ${names(name, spec).join('|')}) words="${spec.words.join(' ')}" ;;`);
+
+  const level3 = Object.entries(COMMANDS).flatMap(([name, spec]) =>
+    Object.entries(spec.args ?? {}).map(([sub, arg]) =>
+      `      ${names(name, spec).map(n => `"${n} ${sub}"`).join('|')}) words=${Array.isArray(arg) ? `"${arg.join(' ')}"` : `"$(${lookup(arg)})"`} ;;`,
+    ));
+
+  return `# vargos bash completion — add to ~/.bashrc:  eval "$(vargos completions bash)"
+_vargos() {
+  # Split on whitespace ourselves — COMP_WORDS also breaks on ':', which session keys contain
+  local line="\${COMP_LINE:0:COMP_POINT}" words="" argv=()
+  read -ra argv <<< "$line"
+  [[ -z "$line" || "$line" == *[[:space:]] ]] && argv+=("")
+  local cword=$(( \${#argv[@]} - 1 ))
+  local cur="\${argv[cword]}"
+  case "$cword" in
+    1) words="${TOP_LEVEL.join(' ')}" ;;
+    2) case "\${argv[1]}" in
+${level2.join('\n')}
+    esac ;;
+    3) case "\${argv[1]} \${argv[2]}" in
+${level3.join('\n')}
+    esac ;;
+  esac
+  COMPREPLY=($(compgen -W "$words" -- "$cur"))
+  # Bash only replaces the text after the last ':' — drop what it already has
+  if [[ "$cur" == *:* && "$COMP_WORDBREAKS" == *:* ]]; then
+    local prefix="\${cur%"\${cur##*:}"}"
+    COMPREPLY=("\${COMPREPLY[@]#"$prefix"}")
+  fi
+}
+complete -F _vargos vargos
+`;
+}
+
+function zsh(): string {
+  const level2 = Object.entries(COMMANDS)
+    .filter(([, spec]) => spec.words.length > 0)
+    .map(([name, spec]) => `        ${names(name, spec).join('|')}) values=(${spec.words.join(' ')}) ;;`);
+
+  const level3 = Object.entries(COMMANDS).flatMap(([name, spec]) =>
+    Object.entries(spec.args ?? {}).map(([sub, arg]) =>
+      `        ${names(name, spec).map(n => `"${n} ${sub}"`).join('|')}) values=(${Array.isArray(arg) ? arg.join(' ') : `\${(f)"$(${lookup(arg)})"}`}) ;;`,
+    ));
+
+  return `#compdef vargos
+# vargos zsh completion — add to ~/.zshrc:  eval "$(vargos completions zsh)"
+_vargos() {
+  local -a values
+  case $CURRENT in
+    2) values=(${TOP_LEVEL.join(' ')}) ;;
+    3) case "$words[2]" in
+${level2.join('\n')}
+      esac ;;
+    4) case "$words[2] $words[3]" in
+${level3.join('\n')}
+      esac ;;
+  esac
+  compadd -a values
+}
+compdef _vargos vargos
+`;
+}
+
+function fish(): string {
+  const lines = [
+    '# vargos fish completion — save as ~/.config/fish/completions/vargos.fish:',
+    '#   vargos completions fish > ~/.config/fish/completions/vargos.fish',
+    'complete -c vargos -f',
+    `complete -c vargos -n '__fish_use_subcommand' -a '${TOP_LEVEL.join(' ')}'`,
+  ];
+
+  for (const [name, spec] of Object.entries(COMMANDS)) {
+    if (spec.words.length === 0) continue;
+    const cmd = names(name, spec).join(' ');
+    lines.push(
+      `complete -c vargos -n '__fish_seen_subcommand_from ${cmd}; and not __fish_seen_subcommand_from ${spec.words.join(' ')}' -a '${spec.words.join(' ')}'`,
+    );
+    for (const [sub, arg] of Object.entries(spec.args ?? {})) {
+      const values = Array.isArray(arg) ? arg.join(' ') : `(${lookup(arg)})`;
+      lines.push(
+        `complete -c vargos -n '__fish_seen_subcommand_from ${cmd}; and __fish_seen_subcommand_from ${sub}' -a '${values}'`,
+      );
+    }
+  }
+
+  return lines.join('\n') + '\n';
+}
+
+export function completionScript(shell: Shell): string {
+  switch (shell) {
+    case 'bash': return bash();
+    case 'zsh': return zsh();
+    case 'fish': return fish();
+  }
+}
@@ -0,0 +1,194 @@
+/**
+ * Config editing — dotted-key get/set on config.json for the vargos config CLI.
+ * `agent`, `providers` and `auth` keys go to agent/settings.json, agent/models.json and
+ * agent/auth.json, where the gateway keeps them.
+ *
+ * Exports:
+ *   resolveConfigValue() → effective value at a dotted key (`gateway.port`, `channels.0.enabled`)
+ *                          plus the layer (system config, config, agent, policy) it comes from
+ *   setConfigValue()    → parse, validate against AppConfigSchema, and write config.json (or agent/*.json)
+ *   parseConfigValue()  → type-aware parsing of a CLI string (number, boolean, null, JSON, string)
+ *   validateConfigFile() → schema issues for config.json as it is on disk (empty = valid)
+ */
+
+import { existsSync, mkdirSync, readFileSync, writeFileSync } from 'node:fs';
+import path from 'node:path';
+import { getDataPaths } from '../lib/paths.js';
+import { deepMerge, isPlainObject, type PlainObject } from '../lib/merge.js';
+import { AppConfigSchema } from '../services/config/schemas/app.js';
+import { readConfigLayers, type ConfigLayers } from '../services/config/layers.js';
+import { CliError, EXIT } from './exit-codes.js';
+
+// ── Helpers ───────────────────────────────────────────────────────────────────
+
+function readJson(file: string): PlainObject {
+  if (!existsSync(file)) return {};
+  let parsed: unknown;
+  try {
+    parsed = JSON.parse(readFileSync(file, 'utf-8'));
+  } catch (err) {
+    throw new CliError(`${file} is not valid JSON: ${err instanceof Error ? err.message : err}`, EXIT.CONFIG);
+  }
+  if (!isPlainObject(parsed)) throw new CliError(`${file} is not a JSON object.`, EXIT.CONFIG);
+  return parsed;
+}
+
+function splitKey(key: string): string[] {
+  const parts = key.split('.');
+  if (parts.some(p => p === '')) throw new CliError(`Invalid key "${key}".`, EXIT.USAGE);
+  return parts;
+}
+
+function getPath(obj: unknown, parts: string[]): unknown {
+  let cur = obj;
+  for (const part of parts) {
+    if (Array.isArray(cur)) cur = cur[Number(part)];
+    else if (isPlainObject(cur)) cur = cur[part];
+    else return undefined;
+  }
+  return cur;
+}
+
+function setPath(obj: PlainObject, parts: string[], value: unknown): void {
+  let cur = obj as Record<string, unknown>;
+  for (const part of parts.slice(0, -1)) {
+    if (!isPlainObject(cur[part]) && !Array.isArray(cur[part])) cur[part] = {};
+    cur = cur[part] as Record<string, unknown>;
+  }
+  const last = parts[parts.length - 1];
+  if (value !== undefined) cur[last] = value;
+  else if (Array.isArray(cur)) cur.splice(Number(last), 1);
+  else delete cur[last];
+}
+
+/** Top-level keys the gateway keeps in agent/*.json rather than config.json (see ConfigService). */
+const AGENT_FILES = { agent: 'settings.json', providers: 'models.json', auth: 'auth.json' } as const;
+type AgentSection = keyof typeof AGENT_FILES;
+
+function isAgentSection(key: string): key is AgentSection {
+  return Object.hasOwn(AGENT_FILES, key);
+}
+
+function agentFile(section: AgentSection): string {
+  return path.join(getDataPaths().dataDir, 'agent', AGENT_FILES[section]);
+}
+
+/** The section as stored — models.json nests providers under `providers`; undefined when absent. */
+function readAgentSection(section: AgentSection): PlainObject | undefined {
+  const file = agentFile(section);
+  if (!existsSync(file)) return undefined;
+  const data = readJson(file);
+  if (section !== 'providers') return data;
+  return isPlainObject(data.providers) ? data.providers : undefined;
+}
+
+function writeAgentSection(section: AgentSection, value: PlainObject): void {
+  const file = agentFile(section);
+  const data = section === 'providers' ? { ...readJson(file), providers: value } : value;
+  if (!existsSync(path.dirname(file))) mkdirSync(path.dirname(file), { recursive: true });
+  writeFileSync(file, JSON.stringify(data, null, 2), { mode: 0o600 });
+}
+
+function issuesOf(config: unknown): string[] {
+  const result = AppConfigSchema.safeParse(config);
+  return result.success ? [] : result.error.issues.map(i => `${i.path.join('.') || '(root)'}: ${i.message}`);
+}
+
+// ── Public API ────────────────────────────────────────────────────────────────
+
+/** `8080` → number, `true` → boolean, `null` → null, `[…]`/`{…}` → JSON, anything else → string. */
+export function parseConfigValue(raw: string): unknown {
+  const trimmed = raw.trim();
+  if (/^(true|false|null)$/.test(trimmed) || /^-?\d+(\.\d+)?$/.test(trimmed) || /^[[{"]/.test(trimmed)) {
+    try {
+      return JSON.parse(trimmed);
+    } catch {
+      // Not valid JSON after all — keep as string
+    }
+  }
+  return raw;
+}
+
+export interface ResolvedValue {
+  value: unknown;
+  /** Highest-precedence layer that sets the key — undefined when nothing does. */
+  layer?: 'system config' | 'config' | 'agent' | 'policy';
+  file?: string;
+}
+
+/**
+ * Effective value as the gateway sees it: system config ← config.json ← agent/*.json ← policy,
+ * combined the way ConfigService.loadConfig does.
+ */
+export function resolveConfigValue(key: string): ResolvedValue {
+  const parts = splitKey(key);
+  const paths = getDataPaths();
+  let layers: ConfigLayers;
+  try {
+    layers = readConfigLayers(paths);
+  } catch (err) {
+    throw new CliError(err instanceof Error ? err.message : String(err), EXIT.CONFIG);
+  }
+
+  const base = deepMerge(layers.system, layers.user);
+  const settings = readAgentSection('agent');
+  const providers = readAgentSection('providers');
+  const auth = readAgentSection('auth');
+  if (settings) base.agent = { ...(isPlainObject(base.agent) ? base.agent : {}), ...settings };
+  if (providers) base.providers = providers;
+  if (auth) base.auth = auth;
+
+  const value = getPath(deepMerge(base, layers.policy), parts);
+  const [section, ...rest] = parts;
+  if (getPath(layers.policy, parts) !== undefined) return { value, layer: 'policy', file: paths.policyFile };
+  if (isAgentSection(section) && getPath(readAgentSection(section), rest) !== undefined) {
+    return { value, layer: 'agent', file: agentFile(section) };
+  }
+  if (getPath(layers.user, parts) !== undefined) return { value, layer: 'config', file: paths.configFile };
+  if (getPath(layers.system, parts) !== undefined) return { value, layer: 'system config', file: paths.systemConfigFile };
+  return { value };
+}
+
+/**
+ * Set (or with `value === undefined`, unset) a key in config.json — or in agent/*.json for
+ * `agent`, `providers` and `auth` keys. Refuses keys the admin policy pins, and writes
+ * nothing if the result fails validation.
+ */
+export function setConfigValue(key: string, value: unknown): void {
+  const { configFile, policyFile } = getDataPaths();
+  const parts = splitKey(key);
+
+  if (existsSync(policyFile) && getPath(readJson(policyFile), parts) !== undefined) {
+    throw new CliError(`"${key}" is locked by admin policy (${policyFile}).`, EXIT.CONFIG);
+  }
+
+  const [section, ...rest] = parts;
+  if (isAgentSection(section)) {
+    let data: unknown = readAgentSection(section) ?? {};
+    if (rest.length === 0) data = value ?? {};
+    else setPath(data as PlainObject, rest, value);
+
+    const issues = isPlainObject(data) ? issuesOf({ [section]: data }) : [`${section}: expected an object`];
+    if (issues.length > 0) throw new CliError(`Invalid value for "${key}":\n  ${issues.join('\n  ')}`, EXIT.CONFIG);
+    writeAgentSection(section, data as PlainObject);
+    return;
+  }
+
+  const config = readJson(configFile);
+  setPath(config, parts, value);
+
+  const issues = issuesOf(config);
+  if (issues.length > 0) throw new CliError(`Invalid value for "${key}":\n  ${issues.join('\n  ')}`, EXIT.CONFIG);
+
+  if (!existsSync(path.dirname(configFile))) mkdirSync(path.dirname(configFile), { recursive: true });
+  writeFileSync(configFile, JSON.stringify(config, null, 2), { mode: 0o600 });
+}
+
+export function validateConfigFile(): string[] {
+  const { configFile } = getDataPaths();
+  try {
+    return issuesOf(readJson(configFile));
+  } catch (err) {
+    return [err instanceof Error ? err.message : String(err)];
+  }
+}
@@ -0,0 +1,37 @@
+/**
+ * CLI exit codes — stable, documented values so scripts can branch on the kind of failure.
+ *
+ * Exports:
+ *   EXIT          → exit code per failure kind (documented in docs/getting-started.md)
+ *   CliError      → Error carrying the exit code the CLI should use
+ *   exitCodeFor() → exit code for any thrown value (CliError code, else EXIT.FAILURE)
+ *
+ * 42 is reserved by the supervisor (index.ts) for process restarts — don't reuse it.
+ */
+
+export const EXIT = {
+  OK: 0,
+  /** Anything not covered below */
+  FAILURE: 1,
+  /** Missing or invalid arguments */
+  USAGE: 2,
+  /** Gateway not running or not reachable */
+  GATEWAY: 3,
+  /** Config invalid, locked by admin policy, or missing a required value */
+  CONFIG: 4,
+  /** Named channel, session, or key does not exist */
+  NOT_FOUND: 5,
+} as const;
+
+export type ExitCode = typeof EXIT[keyof typeof EXIT];
+
+export class CliError extends Error {
+  constructor(message: string, readonly exitCode: ExitCode) {
+    super(message);
+    this.name = 'CliError';
+  }
+}
+
+export function exitCodeFor(err: unknown): ExitCode {
+  return err instanceof CliError ? err.exitCode : EXIT.FAILURE;
+}
@@ -0,0 +1,60 @@
+/**
+ * Gateway client — one-shot JSON-RPC calls to a running `vargos start`.
+ *
+ * Exports:
+ *   gatewayAddress()  → { host, port } resolved from the merged config layers / env, mirroring boot.ts
+ *   gatewayCall()     → send one request and resolve with its result
+ */
+
+import { createConnection } from 'node:net';
+import { mergeLayers, readConfigLayers } from '../services/config/layers.js';
+import { CliError, EXIT } from './exit-codes.js';
+
+/**
+ * Resolve the gateway address the way boot.ts binds it: system config, then the
+ * user's config.json, then admin policy — so an admin-provisioned port is honoured.
+ */
+export function gatewayAddress(): { host: string; port: number } {
+  let gw: { host?: string; port?: number } = {};
+  try {
+    gw = (mergeLayers(readConfigLayers()).gateway ?? {}) as typeof gw;
+  } catch {
+    // Unreadable config — fall back to env/defaults
+  }
+  const host = gw.host ?? process.env.BUS_HOST ?? '127.0.0.1';
+  const port = gw.port ?? (process.env.BUS_PORT ? parseInt(process.env.BUS_PORT, 10) : 9000);
+  return { host, port };
+}
+
+/** Send one JSON-RPC request to the gateway and resolve with its result. */
+export function gatewayCall<T>(method: string, params: unknown, timeoutMs = 10_000): Promise<T> {
+  const { host, port } = gatewayAddress();
+  return new Promise<T>((resolve, reject) => {
+    const socket = createConnection({ host, port }, () => {
+      socket.write(JSON.stringify({ jsonrpc: '2.0', method, params, id: 1 }) + '\n');
+    });
+
+    let buffer = '';
+    socket.on('data', (chunk) => {
+      buffer += chunk.toString();
+      const nl = buffer.indexOf('\n');
+      if (nl === -1) return; // wait for the full line
+      socket.end();
+      try {
+        const res = JSON.parse(buffer.slice(0, nl)) as { result?: T; error?: { message?: string } };
+        if (res.error) reject(new Error(res.error.message ?? 'gateway error'));
+        else resolve(res.result as T);
+      } catch (err) {
+        reject(err instanceof Error ? err : new Error(String(err)));
+      }
+    });
+
+    socket.setTimeout(timeoutMs, () => {
+      socket.destroy();
+      reject(new CliError(`gateway not reachable at ${host}:${port} — is "vargos start" running?`, EXIT.GATEWAY));
+    });
+    socket.on('error', (err) => reject(
+      new CliError(`gateway not reachable at ${host}:${port} — is "vargos start" running? (${err.message})`, EXIT.GATEWAY),
+    ));
+  });
+}
@@ -0,0 +1,198 @@
+/**
+ * Session management for the vargos sessions CLI — reads Pi SDK session files, deletes them,
+ * and keeps local annotation sidecars next to them.
+ *
+ * Exports:
+ *   sessionDir()           → on-disk directory for a sessionKey (`:` → path separator, must stay inside sessionsDir)
+ *   listSessions()         → every stored session with file count and last activity
+ *   deleteSession()        → remove a session's JSONL files and their annotation sidecars
+ *   listSessionFiles()     → JSONL files for a sessionKey, newest first
+ *   readSessionEntries()   → parsed JSONL entries (malformed lines kept as { raw })
+ *   describeEntry()        → one-line label for an entry (type, role, timestamp)
+ *   entryText()            → { role, text } for message entries, else undefined
+ *   formatHistory()        → the `sessions show` transcript, each entry's notes under it
+ *   readAnnotations()      → local notes for a session file, keyed by 1-based step
+ *   addAnnotation()        → append a local note to one step of a session file
+ */
+
+import { existsSync, readdirSync, readFileSync, rmSync, statSync, writeFileSync } from 'node:fs';
+import path from 'node:path';
+import { getDataPaths } from '../lib/paths.js';
+import { CliError, EXIT } from './exit-codes.js';
+
+// ── Types ─────────────────────────────────────────────────────────────────────
+
+export interface SessionFile {
+  name: string;
+  path: string;
+  mtime: Date;
+  size: number;
+}
+
+export interface SessionSummary {
+  sessionKey: string;
+  files: number;
+  lastActive: Date;
+}
+
+export type SessionEntry = Record<string, unknown>;
+
+/** Local-only notes, keyed by 1-based step number as shown by `sessions step`. */
+export type Annotations = Record<string, string[]>;
+
+// ── Public API ────────────────────────────────────────────────────────────────
+
+/**
+ * Mirrors AgentService.getOrCreateSession — `:` in the key becomes a directory level.
+ * Keys with `..` segments or that resolve outside sessionsDir are rejected, so
+ * `sessions delete ../logs` can't reach other data.
+ */
+export function sessionDir(sessionKey: string): string {
+  const root = path.resolve(getDataPaths().sessionsDir);
+  const segments = sessionKey.split(/[:/\\]/);
+  const dir = path.resolve(root, ...segments);
+  if (segments.includes('..') || !dir.startsWith(root + path.sep)) {
+    throw new CliError(`Invalid session key "${sessionKey}".`, EXIT.USAGE);
+  }
+  return dir;
+}
+
+/** Walk the sessions dir; any directory holding JSONL files is a session. Newest first. */
+export function listSessions(): SessionSummary[] {
+  const root = getDataPaths().sessionsDir;
+  const out: SessionSummary[] = [];
+
+  const walk = (dir: string) => {
+    let entries;
+    try {
+      entries = readdirSync(dir, { withFileTypes: true });
+    } catch {
+      return;
+    }
+    const jsonl = entries.filter(e => e.isFile() && e.name.endsWith('.jsonl'));
+    if (jsonl.length > 0) {
+      const lastActive = new Date(Math.max(...jsonl.map(e => statSync(path.join(dir, e.name)).mtime.getTime())));
+      out.push({ sessionKey: path.relative(root, dir).split(path.sep).join(':'), files: jsonl.length, lastActive });
+    }
+    for (const e of entries) {
+      if (e.isDirectory() && !e.name.startsWith('.')) walk(path.join(dir, e.name));
+    }
+  };
+
+  walk(root);
+  return out.sort((a, b) => b.lastActive.getTime() - a.lastActive.getTime());
+}
+
+/**
+ * Remove a session's files. Only the session's own directory level is touched —
+ * nested sessions (e.g. `<key>:subagent:<child>`) are kept.
+ */
+export function deleteSession(sessionKey: string): number {
+  const files = listSessionFiles(sessionKey);
+  if (files.length === 0) throw new CliError(`Session "${sessionKey}" not found.`, EXIT.NOT_FOUND);
+  for (const f of files) {
+    rmSync(f.path, { force: true });
+    rmSync(annotationsPath(f.path), { force: true });
+  }
+  return files.length;
+}
+
+export function listSessionFiles(sessionKey: string): SessionFile[] {
+  const dir = sessionDir(sessionKey);
+  if (!existsSync(dir)) return [];
+  return readdirSync(dir)
+    .filter(name => name.endsWith('.jsonl'))
+    .map((name) => {
+      const fp = path.join(dir, name);
+      const st = statSync(fp);
+      return { name, path: fp, mtime: st.mtime, size: st.size };
+    })
+    .sort((a, b) => b.mtime.getTime() - a.mtime.getTime());
+}
+
+/** Parse every line; a malformed line becomes `{ raw }` so stepping still shows it. */
+export function readSessionEntries(filePath: string): SessionEntry[] {
+  return readFileSync(filePath, 'utf-8')
+    .split('\n')
+    .filter(line => line.trim())
+    .map((line) => {
+      try {
+        const parsed = JSON.parse(line) as unknown;
+        return typeof parsed === 'object' && parsed !== null ? parsed as SessionEntry : { raw: line };
+      } catch {
+        return { raw: line };
+      }
+    });
+}
+
+export function describeEntry(entry: SessionEntry): string {
+  if ('raw' in entry && Object.keys(entry).length === 1) return 'unparsed line';
+  const message = entry.message as { role?: string } | undefined;
+  const type = typeof entry.type === 'string' ? entry.type : undefined;
+  const role = message?.role ?? (typeof entry.role === 'string' ? entry.role : undefined);
+  const label = [type, role].filter(Boolean).join('/') || 'entry';
+  return typeof entry.timestamp === 'string' ? `${label} · ${entry.timestamp}` : label;
+}
+
+/** Text of a message entry — Pi's `message.content` (string or text parts) or a flat `content`. */
+export function entryText(entry: SessionEntry): { role: string; text: string } | undefined {
+  const message = (entry.message ?? entry) as { role?: unknown; content?: unknown };
+  if (typeof message.role !== 'string') return undefined;
+
+  const { content } = message;
+  const text = typeof content === 'string'
+    ? content
+    : Array.isArray(content)
+      ? content
+        .filter((p): p is { type: string; text: string } => p?.type === 'text' && typeof p.text === 'string')
+        .map(p => p.text)
+        .join('\n')
+      : '';
+  return text.trim() ? { role: message.role, text } : undefined;
+}
+
+/**
+ * Message text per entry, each followed by its notes (keyed by the same 1-based index as
+ * `sessions step`). A non-message entry is only shown when it carries a note.
+ */
+export function formatHistory(entries: SessionEntry[], notes: Annotations): string {
+  const blocks: string[] = [];
+  entries.forEach((entry, i) => {
+    const msg = entryText(entry);
+    const entryNotes = (notes[String(i + 1)] ?? []).map(note => `  📝 ${note}`);
+    if (!msg && entryNotes.length === 0) return;
+    const head = msg ? `[${msg.role}] ${msg.text}` : `[step ${i + 1} · ${describeEntry(entry)}]`;
+    blocks.push([head, ...entryNotes].join('\n'));
+  });
+  return blocks.join('\n\n');
+}
+
+// ── Annotations ───────────────────────────────────────────────────────────────
+
+/**
+ * Notes live in a sidecar next to the session file (`<name>.annotations.json`), never in the
+ * JSONL itself — Pi SDK owns that file and the memory indexer only globs `*.jsonl`.
+ */
+export function annotationsPath(filePath: string): string {
+  return filePath.replace(/\.jsonl$/, '') + '.annotations.json';
+}
+
+export function readAnnotations(filePath: string): Annotations {
+  const fp = annotationsPath(filePath);
+  if (!existsSync(fp)) return {};
+  try {
+    return JSON.parse(readFileSync(fp, 'utf-8')) as Annotations;
+  } catch {
+    return {};
+  }
+}
+
+export function addAnnotation(filePath: string, step: number, note: string): void {
+  const total = readSessionEntries(filePath).length;
+  if (!Number.isInteger(step) || step < 1 || step > total) {
+    throw new CliError(`Step ${step} out of range — ${path.basename(filePath)} has ${total} entries.`, EXIT.USAGE);
+  }
+  const annotations = readAnnotations(filePath);
+  (annotations[String(step)] ??= []).push(note);
+  writeFileSync(annotationsPath(filePath), JSON.stringify(annotations, null, 2));
+}
@@ -201,4 +201,15 @@ describe('EventEmitterBus', () => {
       expect(log).toEqual(['tool', 'completed']);
     });
   });
+
+  describe('bus.status', () => {
+    it('reports the gateway version alongside registered services', async () => {
+      const bus = new EventEmitterBus();
+      bus.onRestart('config', async () => {});
+
+      const status = await bus.status();
+      expect(status.version).toMatch(/^\d+\.\d+\.\d+/);
+      expect(status.services).toEqual([{ name: 'config', status: 'running' }]);
+    });
+  });
 });
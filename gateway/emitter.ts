@@ -6,6 +6,7 @@ import type { Bus, HandlerOf, CallableEventKey, PureEventKey } from './bus.js';
 import { register, HANDLERS, TOOLS, type ToolSchema } from './decorators.js';
 import type { EventParams, EventResult } from './bus.js';
 import { createLogger } from '../lib/logger.js';
+import { packageVersion } from '../lib/version.js';
 import { zodToJsonSchema } from 'zod-to-json-schema';
 
 const log = createLogger('bus');
@@ -176,7 +177,7 @@ export class EventEmitterBus implements Bus {
   }
 
   @register('bus.status', {
-    description: 'List all registered services and their current status.',
+    description: 'Report the gateway version and list all registered services with their current status.',
     schema: z.object({}).default({}),
   })
   async status(_params?: EventMap['bus.status']['params']): Promise<EventMap['bus.status']['result']> {
@@ -184,7 +185,7 @@ export class EventEmitterBus implements Bus {
       name,
       status: 'running' as const,
     }));
-    return { services };
+    return { version: packageVersion(), services };
   }
 
   // ── Bus introspection ─────────────────────────────────────────────────────────
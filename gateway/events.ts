@@ -158,5 +158,5 @@ export interface EventMap {
   'bus.restart': { params: { service: string }; result: { ok: boolean } };
 
   /** List all registered services and their status. */
-  'bus.status': { params: Record<string, never>; result: { services: Array<{ name: string; status: string }> } };
+  'bus.status': { params: Record<string, never>; result: { version: string; services: Array<{ name: string; status: string }> } };
 }
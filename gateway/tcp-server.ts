@@ -135,8 +135,10 @@ async function handleRequest(
     return;
   }
 
+  const started = Date.now();
   try {
     const result = await bus.call(method as CallableEventKey, params as never);
+    log.debug(`rpc ${method} ok (${Date.now() - started}ms)`);
     socket.write(
       JSON.stringify({
         jsonrpc: '2.0',
@@ -146,6 +148,7 @@ async function handleRequest(
     );
     socket.end();
   } catch (err) {
+    log.debug(`rpc ${method} failed (${Date.now() - started}ms): ${err instanceof Error ? err.message : String(err)}`);
     socket.write(
       JSON.stringify({
         jsonrpc: '2.0',
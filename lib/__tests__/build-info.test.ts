@@ -0,0 +1,64 @@
+import { describe, it, expect } from 'vitest';
+import { mkdirSync, rmSync, writeFileSync } from 'node:fs';
+import * as os from 'node:os';
+import * as path from 'node:path';
+import { enabledFeatures, getBuildInfo } from '../build-info.js';
+import { packageVersion } from '../version.js';
+
+const noDir = path.join(os.tmpdir(), `build-info-none-${process.pid}`);
+const noDirs = { agentDir: noDir, cronDir: noDir };
+
+describe('enabledFeatures', () => {
+  it('lists enabled channel types once each', () => {
+    const features = enabledFeatures({
+      channels: [
+        { id: 'a', type: 'telegram' },
+        { id: 'b', type: 'telegram' },
+        { id: 'c', type: 'whatsapp', enabled: false },
+      ],
+      heartbeat: { enabled: false },
+      linkExpand: { enabled: false },
+    }, noDirs);
+    expect(features).toEqual(['telegram']);
+  });
+
+  it('treats link expansion as on by default', () => {
+    expect(enabledFeatures({}, noDirs)).toEqual(['link-expand']);
+  });
+
+  it('reports heartbeat only when an enabled heartbeat task exists', () => {
+    const cronDir = path.join(os.tmpdir(), `build-info-cron-${process.pid}-${Date.now()}`);
+    mkdirSync(cronDir, { recursive: true });
+    const task = (enabled: boolean) => `---\nid: heartbeat\nschedule: "*/30 * * * *"\nenabled: ${enabled}\n---\n\nCheck in`;
+    try {
+      writeFileSync(path.join(cronDir, 'heartbeat.md'), task(false));
+      expect(enabledFeatures({ linkExpand: { enabled: false } }, { agentDir: noDir, cronDir })).toEqual([]);
+      writeFileSync(path.join(cronDir, 'heartbeat.md'), task(true));
+      expect(enabledFeatures({ linkExpand: { enabled: false } }, { agentDir: noDir, cronDir })).toEqual(['heartbeat']);
+    } finally {
+      rmSync(cronDir, { recursive: true, force: true });
+    }
+  });
+
+  it('reports mcp and webhooks when configured', () => {
+    const features = enabledFeatures({
+      mcpServers: { github: {} },
+      webhooks: [{ id: 'w' }],
+      heartbeat: { enabled: false },
+      linkExpand: { enabled: false },
+    }, noDirs);
+    expect(features).toEqual(['mcp', 'webhooks']);
+  });
+});
+
+describe('getBuildInfo', () => {
+  it('derives features from the config it is given', () => {
+    const info = getBuildInfo(noDir, {
+      channels: [{ id: 'a', type: 'whatsapp' }],
+      heartbeat: { enabled: false },
+      linkExpand: { enabled: false },
+    });
+    expect(info.version).toBe(packageVersion());
+    expect(info.features).toEqual(['whatsapp']);
+  });
+});
@@ -0,0 +1,43 @@
+import { describe, it, expect, afterEach } from 'vitest';
+import { isLevelEnabled, parseLogLevel } from '../logger.js';
+
+describe('isLevelEnabled', () => {
+  const original = process.env.LOG_LEVEL;
+  afterEach(() => {
+    if (original === undefined) delete process.env.LOG_LEVEL;
+    else process.env.LOG_LEVEL = original;
+  });
+
+  it('defaults to info', () => {
+    delete process.env.LOG_LEVEL;
+    expect(isLevelEnabled('debug')).toBe(false);
+    expect(isLevelEnabled('info')).toBe(true);
+    expect(isLevelEnabled('error')).toBe(true);
+  });
+
+  it('treats LOG_LEVEL as a threshold', () => {
+    process.env.LOG_LEVEL = 'warn';
+    expect(isLevelEnabled('info')).toBe(false);
+    expect(isLevelEnabled('warn')).toBe(true);
+    process.env.LOG_LEVEL = 'DEBUG';
+    expect(isLevelEnabled('debug')).toBe(true);
+  });
+
+  it('falls back to info on unknown levels', () => {
+    process.env.LOG_LEVEL = 'verbose';
+    expect(isLevelEnabled('debug')).toBe(false);
+    expect(isLevelEnabled('info')).toBe(true);
+  });
+});
+
+describe('parseLogLevel', () => {
+  it('normalises case and whitespace', () => {
+    expect(parseLogLevel(' WARN ')).toBe('warn');
+    expect(parseLogLevel('debug')).toBe('debug');
+  });
+
+  it('returns undefined for missing or unknown values', () => {
+    expect(parseLogLevel(undefined)).toBeUndefined();
+    expect(parseLogLevel('trace')).toBeUndefined();
+  });
+});
@@ -0,0 +1,55 @@
+import { describe, it, expect } from 'vitest';
+import { applyChanges, deepMerge } from '../merge.js';
+
+describe('deepMerge', () => {
+  it('merges nested objects key by key', () => {
+    expect(deepMerge(
+      { gateway: { host: '0.0.0.0', port: 9000 } },
+      { gateway: { port: 9100 } },
+    )).toEqual({ gateway: { host: '0.0.0.0', port: 9100 } });
+  });
+
+  it('replaces arrays and scalars wholesale', () => {
+    expect(deepMerge({ channels: [{ id: 'a' }], x: 1 }, { channels: [], x: 2 }))
+      .toEqual({ channels: [], x: 2 });
+  });
+
+  it('ignores undefined overrides', () => {
+    expect(deepMerge({ a: 1 }, { a: undefined })).toEqual({ a: 1 });
+  });
+
+  it('does not mutate its inputs', () => {
+    const base = { a: { b: 1 } };
+    deepMerge(base, { a: { b: 2 } });
+    expect(base).toEqual({ a: { b: 1 } });
+  });
+});
+
+describe('applyChanges', () => {
+  it('writes only the leaves that changed', () => {
+    expect(applyChanges(
+      { gateway: { port: 9100 } },
+      { gateway: { host: '0.0.0.0', port: 9100 }, linkExpand: { enabled: false, maxUrls: 3 } },
+      { gateway: { host: '0.0.0.0', port: 9200 }, linkExpand: { enabled: false, maxUrls: 3 } },
+    )).toEqual({ gateway: { port: 9200 } });
+  });
+
+  it('creates nested objects the target does not have yet', () => {
+    expect(applyChanges({}, { linkExpand: { enabled: true, maxUrls: 3 } }, { linkExpand: { enabled: false, maxUrls: 3 } }))
+      .toEqual({ linkExpand: { enabled: false } });
+  });
+
+  it('replaces arrays and removes dropped keys', () => {
+    expect(applyChanges(
+      { channels: [{ id: 'a' }], mcpServers: { x: {}, y: {} } },
+      { channels: [{ id: 'a' }], mcpServers: { x: {}, y: {} } },
+      { channels: [], mcpServers: { x: {} } },
+    )).toEqual({ channels: [], mcpServers: { x: {} } });
+  });
+
+  it('does not mutate its inputs', () => {
+    const target = { a: { b: 1 } };
+    applyChanges(target, { a: { b: 1 } }, { a: { b: 2 } });
+    expect(target).toEqual({ a: { b: 1 } });
+  });
+});
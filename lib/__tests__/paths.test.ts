@@ -0,0 +1,17 @@
+import { describe, it, expect, afterEach } from 'vitest';
+import { getDataPaths, resetDataPaths, POLICY_FILE } from '../paths.js';
+
+describe('getDataPaths', () => {
+  const saved = process.env.VARGOS_POLICY_FILE;
+  afterEach(() => {
+    if (saved === undefined) delete process.env.VARGOS_POLICY_FILE;
+    else process.env.VARGOS_POLICY_FILE = saved;
+    resetDataPaths();
+  });
+
+  it('keeps the policy at its fixed path whatever the environment says', () => {
+    process.env.VARGOS_POLICY_FILE = '/nonexistent/policy.json';
+    resetDataPaths();
+    expect(getDataPaths().policyFile).toBe(POLICY_FILE);
+  });
+});
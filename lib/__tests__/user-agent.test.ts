@@ -0,0 +1,20 @@
+import { describe, it, expect } from 'vitest';
+import { CLIENT_HEADER, defaultUserAgent, outboundHeaders } from '../user-agent.js';
+import { packageVersion } from '../version.js';
+
+describe('outboundHeaders', () => {
+  it('identifies vargos, its version, and the calling feature by default', () => {
+    const headers = outboundHeaders('web-fetch');
+    expect(headers['User-Agent']).toBe(defaultUserAgent());
+    expect(headers['User-Agent']).toMatch(new RegExp(`^vargos/${packageVersion()} \\(.+; node .+\\)$`));
+    expect(headers[CLIENT_HEADER]).toBe(`web-fetch/${packageVersion()}`);
+  });
+
+  it('honours a configured user agent', () => {
+    expect(outboundHeaders('link-expand', { userAgent: 'acme-bot/1.0' })['User-Agent']).toBe('acme-bot/1.0');
+  });
+
+  it('drops the client header when disabled', () => {
+    expect(outboundHeaders('link-expand', { clientHeader: false })).not.toHaveProperty(CLIENT_HEADER);
+  });
+});
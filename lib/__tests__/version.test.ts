@@ -0,0 +1,8 @@
+import { describe, it, expect } from 'vitest';
+import { packageVersion } from '../version.js';
+
+describe('packageVersion', () => {
+  it('reads the version from package.json', () => {
+    expect(packageVersion()).toMatch(/^\d+\.\d+\.\d+/);
+  });
+});
@@ -0,0 +1,121 @@
+/**
+ * Build & environment info for `vargos version --verbose` and support requests.
+ *
+ * Git sha and build date come from `build-info.json`, written next to cli.js by
+ * `pnpm build` (scripts/build-info.ts). Running from a source checkout falls back
+ * to asking git directly.
+ */
+
+import { execFileSync } from 'node:child_process';
+import { existsSync, readdirSync, readFileSync } from 'node:fs';
+import os from 'node:os';
+import path from 'node:path';
+import { parseFrontmatter } from './frontmatter.js';
+import { getDataPaths } from './paths.js';
+import { packageVersion } from './version.js';
+
+export const BUILD_INFO_FILE = 'build-info.json';
+
+export interface BuildInfo {
+  version: string;
+  gitSha?: string;
+  buildDate?: string;
+  node: string;
+  os: string;
+  dataDir: string;
+  configFile: string;
+  systemConfigFile: string;
+  policyFile: string;
+  /** Optional subsystems switched on in the effective config (channel types, heartbeat, …) */
+  features: string[];
+}
+
+/** Short HEAD sha of the checkout at `dir`, or undefined outside a git repo. */
+export function gitSha(dir: string): string | undefined {
+  try {
+    return execFileSync('git', ['rev-parse', '--short', 'HEAD'], { cwd: dir, stdio: ['ignore', 'pipe', 'ignore'] })
+      .toString().trim() || undefined;
+  } catch {
+    return undefined;
+  }
+}
+
+/** The cron service only runs a heartbeat when an enabled task with id `heartbeat` is on disk. */
+function hasHeartbeatTask(cronDir: string): boolean {
+  let files: string[];
+  try {
+    files = readdirSync(cronDir).filter(f => f.endsWith('.md'));
+  } catch {
+    return false;
+  }
+  return files.some((file) => {
+    try {
+      const meta = parseFrontmatter(readFileSync(path.join(cronDir, file), 'utf8'))?.meta;
+      return meta?.id === 'heartbeat' && meta.enabled === true;
+    } catch {
+      return false;
+    }
+  });
+}
+
+/** Derive enabled features from a raw config object plus the agent and cron dirs. */
+export function enabledFeatures(
+  config: Record<string, unknown>,
+  dirs: { agentDir: string; cronDir: string },
+): string[] {
+  const features = new Set<string>();
+
+  const channels = Array.isArray(config.channels) ? config.channels as Array<Record<string, unknown>> : [];
+  for (const ch of channels) {
+    if (ch.enabled !== false && typeof ch.type === 'string') features.add(ch.type);
+  }
+
+  const enabled = (key: string) => (config[key] as { enabled?: boolean } | undefined)?.enabled !== false;
+  if (hasHeartbeatTask(dirs.cronDir)) features.add('heartbeat');
+  if (enabled('linkExpand')) features.add('link-expand');
+
+  const mcpServers = config.mcpServers as Record<string, unknown> | undefined;
+  if ((mcpServers && Object.keys(mcpServers).length > 0) || existsSync(path.join(dirs.agentDir, 'mcp.json'))) {
+    features.add('mcp');
+  }
+  if (Array.isArray(config.webhooks) && config.webhooks.length > 0) features.add('webhooks');
+
+  return [...features];
+}
+
+/**
+ * `config` is the effective (merged) config — the caller resolves the layers, since
+ * lib/ can't reach the config service. Without it, features come from config.json alone.
+ */
+export function getBuildInfo(rootDir: string, config?: Record<string, unknown>): BuildInfo {
+  const { dataDir, cronDir, configFile, systemConfigFile, policyFile } = getDataPaths();
+
+  let stamped: { gitSha?: string; buildDate?: string } = {};
+  try {
+    stamped = JSON.parse(readFileSync(path.join(rootDir, BUILD_INFO_FILE), 'utf8'));
+  } catch {
+    // Not a built dist — fall back to git below
+  }
+
+  if (!config) {
+    try {
+      config = JSON.parse(readFileSync(configFile, 'utf8')) as Record<string, unknown>;
+    } catch {
+      // First run or unreadable — report no features
+      config = {};
+    }
+  }
+
+  return {
+    version: packageVersion(),
+    gitSha: stamped.gitSha ?? gitSha(rootDir),
+    buildDate: stamped.buildDate,
+    node: process.versions.node,
+    os: `${os.type()} ${os.release()} (${process.platform}/${process.arch})`,
+    dataDir,
+    configFile,
+    systemConfigFile,
+    policyFile,
+    features: enabledFeatures(config, { agentDir: path.join(dataDir, 'agent'), cronDir }),
+  };
+}
@@ -1,5 +1,19 @@
 import type { Bus } from '../gateway/bus.js';
-import type { Json } from '../gateway/events.js';
+import type { Json, LogLevel } from '../gateway/events.js';
+
+const LEVELS: readonly LogLevel[] = ['debug', 'info', 'warn', 'error'];
+
+/** Case-insensitive level name → LogLevel, or undefined when it isn't one. */
+export function parseLogLevel(value: string | undefined): LogLevel | undefined {
+  const level = value?.trim().toLowerCase() as LogLevel | undefined;
+  return level && LEVELS.includes(level) ? level : undefined;
+}
+
+/** Whether `level` passes the $LOG_LEVEL threshold (default: info). Read per call so tests/CLI can set it late. */
+export function isLevelEnabled(level: LogLevel): boolean {
+  const threshold = parseLogLevel(process.env.LOG_LEVEL) ?? 'info';
+  return LEVELS.indexOf(level) >= LEVELS.indexOf(threshold);
+}
 
 let _bus: Bus | null = null;
 
@@ -16,10 +30,10 @@ export function ts(): string {
 }
 
 export function createLogger(service: string) {
-  function write(level: 'debug' | 'info' | 'warn' | 'error', message: string, data?: Json) {
+  function write(level: LogLevel, message: string, data?: Json) {
     if (_bus) {
       _bus.emit('log.onLog', { level, service, message, ...(data !== undefined ? { data } : {}) });
-    } else {
+    } else if (isLevelEnabled(level)) {
       console.error(`${ts()} [${service}] ${level.toUpperCase()} ${message}`, data ?? '');
     }
   }
@@ -0,0 +1,54 @@
+/**
+ * Deep merge for layered JSON config, and the reverse: writing a change back into one layer.
+ *
+ * Plain objects are merged key by key; arrays and scalars in `override`
+ * replace the value in `base` wholesale. Neither input is mutated.
+ *
+ * Example:
+ *   deepMerge({ gateway: { host: '0.0.0.0', port: 9000 } }, { gateway: { port: 9100 } })
+ *     → { gateway: { host: '0.0.0.0', port: 9100 } }
+ */
+
+import { isDeepStrictEqual } from 'node:util';
+
+export type PlainObject = Record<string, unknown>;
+
+export function isPlainObject(value: unknown): value is PlainObject {
+  return typeof value === 'object' && value !== null && !Array.isArray(value);
+}
+
+export function deepMerge(base: PlainObject, override: PlainObject): PlainObject {
+  const out: PlainObject = { ...base };
+  for (const [key, value] of Object.entries(override)) {
+    if (value === undefined) continue;
+    const prev = out[key];
+    out[key] = isPlainObject(prev) && isPlainObject(value) ? deepMerge(prev, value) : value;
+  }
+  return out;
+}
+
+/**
+ * Three-way update of one layer: apply the leaves that differ between `before` and
+ * `after` (both merged views) onto `target`, leaving the rest of `target` untouched.
+ * Keys dropped from `after` are removed from `target`. Neither input is mutated.
+ *
+ * Example — the user edits gateway.port while gateway.host comes from another layer:
+ *   applyChanges({ gateway: { port: 9100 } }, { gateway: { host: 'h', port: 9100 } }, { gateway: { host: 'h', port: 9200 } })
+ *     → { gateway: { port: 9200 } }
+ */
+export function applyChanges(target: PlainObject, before: PlainObject, after: PlainObject): PlainObject {
+  const out: PlainObject = { ...target };
+  for (const [key, value] of Object.entries(after)) {
+    if (value === undefined) continue;
+    const prev = before[key];
+    if (isDeepStrictEqual(prev, value)) continue;
+    const current = out[key];
+    out[key] = isPlainObject(prev) && isPlainObject(value)
+      ? applyChanges(isPlainObject(current) ? current : {}, prev, value)
+      : value;
+  }
+  for (const key of Object.keys(before)) {
+    if (after[key] === undefined) delete out[key];
+  }
+  return out;
+}
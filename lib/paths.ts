@@ -10,9 +10,16 @@ export interface DataPaths {
   logsDir: string;
   cacheDir: string;
   configFile: string;
+  /** Admin-provisioned config layer, merged beneath the user's config.json. */
+  systemConfigFile: string;
+  /** Admin policy, merged above user config — fixed path, so the user's environment can't move it. */
+  policyFile: string;
 }
 
+export const POLICY_FILE = '/etc/vargos/policy.json';
+
 let _cache: DataPaths | null = null;
+let _overrides: Partial<DataPaths> = {};
 
 /** Cached singleton — reads $VARGOS_DATA_DIR or ~/.vargos on first call. */
 export function getDataPaths(): DataPaths {
@@ -37,11 +44,18 @@ export function getDataPaths(): DataPaths {
     logsDir: path.join(dataDir, 'logs'),
     cacheDir: path.join(base, 'vargos'),
     configFile: path.join(dataDir, 'config.json'),
+    systemConfigFile: process.env.VARGOS_SYSTEM_CONFIG?.trim() || '/etc/vargos/config.json',
+    policyFile: POLICY_FILE,
+    ..._overrides,
   };
 
   return _cache;
 }
 
-export function resetDataPaths(): void { _cache = null; }
+/** Drop the cache. Tests pass `overrides` to point fixed paths (the policy file) at a temp dir. */
+export function resetDataPaths(overrides: Partial<DataPaths> = {}): void {
+  _cache = null;
+  _overrides = overrides;
+}
 
 
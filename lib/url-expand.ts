@@ -53,9 +53,9 @@ export interface FetchedContent {
  */
 export async function fetchUrlContent(
   url: string,
-  opts: { maxChars?: number; timeoutMs?: number } = {},
+  opts: { maxChars?: number; timeoutMs?: number; headers?: Record<string, string> } = {},
 ): Promise<FetchedContent | null> {
-  const { maxChars = 8000, timeoutMs = 5000 } = opts;
+  const { maxChars = 8000, timeoutMs = 5000, headers } = opts;
 
   try {
     const controller = new AbortController();
@@ -63,7 +63,7 @@ export async function fetchUrlContent(
 
     let response: Response;
     try {
-      response = await fetch(url, { signal: controller.signal });
+      response = await fetch(url, { signal: controller.signal, headers });
     } finally {
       clearTimeout(timer);
     }
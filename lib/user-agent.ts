@@ -0,0 +1,26 @@
+/**
+ * Outbound HTTP identification for requests vargos makes on its own behalf.
+ * No domain imports — safe to use from any layer.
+ */
+
+import os from 'node:os';
+import { packageVersion } from './version.js';
+
+export const CLIENT_HEADER = 'X-Vargos-Client';
+
+export function defaultUserAgent(): string {
+  return `vargos/${packageVersion()} (${os.platform()} ${os.arch()}; node ${process.versions.node})`;
+}
+
+/**
+ * Headers for an outbound request made by `feature` (e.g. `web-fetch`, `link-expand`).
+ * `userAgent` overrides the default; `clientHeader: false` drops X-Vargos-Client.
+ */
+export function outboundHeaders(
+  feature: string,
+  opts: { userAgent?: string; clientHeader?: boolean } = {},
+): Record<string, string> {
+  const headers: Record<string, string> = { 'User-Agent': opts.userAgent ?? defaultUserAgent() };
+  if (opts.clientHeader !== false) headers[CLIENT_HEADER] = `${feature}/${packageVersion()}`;
+  return headers;
+}
@@ -0,0 +1,25 @@
+/**
+ * The running vargos version — one source for the CLI, the gateway (bus.status),
+ * build info, and outbound User-Agent headers, so `version --verbose` compares like with like.
+ * No domain imports — safe to use from any layer.
+ */
+
+import { existsSync, readFileSync } from 'node:fs';
+import path from 'node:path';
+import { fileURLToPath } from 'node:url';
+
+let cachedVersion: string | undefined;
+
+/** Version from the nearest package.json above this module (source checkout or dist). */
+export function packageVersion(): string {
+  if (cachedVersion) return cachedVersion;
+  let dir = path.dirname(fileURLToPath(import.meta.url));
+  for (let i = 0; i < 3; i++, dir = path.dirname(dir)) {
+    const pkg = path.join(dir, 'package.json');
+    if (existsSync(pkg)) {
+      cachedVersion = (JSON.parse(readFileSync(pkg, 'utf8')) as { version?: string }).version ?? 'unknown';
+      return cachedVersion;
+    }
+  }
+  return (cachedVersion = 'unknown');
+}
@@ -0,0 +1,8 @@
+import { writeFileSync } from 'node:fs';
+import path from 'node:path';
+import { BUILD_INFO_FILE, gitSha } from '../lib/build-info.js';
+
+// Stamp dist/ with the commit and time of this build — read by `vargos version --verbose`
+const root = path.join(import.meta.dirname, '..');
+const info = { gitSha: gitSha(root), buildDate: new Date().toISOString() };
+writeFileSync(path.join(root, 'dist', BUILD_INFO_FILE), JSON.stringify(info, null, 2) + '\n');
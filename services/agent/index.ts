@@ -14,7 +14,7 @@ import { register } from '../../gateway/decorators.js';
 import type { Bus } from '../../gateway/bus.js';
 import type { EventMap, Json } from '../../gateway/events.js';
 import type { AppConfig } from '../../services/config/index.js';
-import { createLogger } from '../../lib/logger.js';
+import { createLogger, isLevelEnabled } from '../../lib/logger.js';
 import { parseDirectives } from './directives.js';
 import { withTimeout } from '../../lib/timeout.js';
 import { interpolatePrompt } from './prompt-interpolate.js';
@@ -325,7 +325,7 @@ export class AgentService {
       ...(model && { model }),
     });
 
-    if (process.env.LOG_LEVEL === 'debug') {
+    if (isLevelEnabled('debug')) {
       const debugDir = path.join(sessionDir, '.debug');
       if (!existsSync(debugDir)) {
         await fs.mkdir(debugDir, { recursive: true });
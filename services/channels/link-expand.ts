@@ -4,21 +4,23 @@
  */
 
 import { extractUrls, isAllowedUrl, fetchUrlContent } from '../../lib/url-expand.js';
-import type { LinkExpandConfig } from '../../services/config/index.js';
+import { outboundHeaders } from '../../lib/user-agent.js';
+import type { HttpConfig, LinkExpandConfig } from '../../services/config/index.js';
 
 export type { LinkExpandConfig };
 
-export async function expandLinks(content: string, config?: LinkExpandConfig): Promise<string> {
+export async function expandLinks(content: string, config?: LinkExpandConfig, http?: HttpConfig): Promise<string> {
   if (!config) return content;
   if (config.enabled === false) return content;
 
   const { maxUrls, maxCharsPerUrl, timeoutMs } = config;
+  const headers = outboundHeaders('link-expand', http);
 
   const urls = extractUrls(content, maxUrls).filter(isAllowedUrl);
   if (urls.length === 0) return content;
 
   const results = await Promise.allSettled(
-    urls.map((url) => fetchUrlContent(url, { maxChars: maxCharsPerUrl, timeoutMs })),
+    urls.map((url) => fetchUrlContent(url, { maxChars: maxCharsPerUrl, timeoutMs, headers })),
   );
 
   const expansions: string[] = [];
@@ -62,7 +62,7 @@ export class InboundMessagePipeline {
     // Expand links in text content
     let enrichedContent = message.text || '';
     if (enrichedContent) {
-      enrichedContent = await expandLinks(enrichedContent, this.config.linkExpand).catch(() => enrichedContent);
+      enrichedContent = await expandLinks(enrichedContent, this.config.linkExpand, this.config.http).catch(() => enrichedContent);
     }
 
     // Extract execution-relevant fields from channel config
@@ -336,4 +336,165 @@ describe('ConfigService E2E', () => {
       expect(result.agent.model).toBe('anthropic:from-settings');
     });
   });
+
+  describe('system config layer', () => {
+    async function setupWithSystemLayer(system: Record<string, unknown>) {
+      const dir = path.join(os.tmpdir(), `config-test-system-${Date.now()}-${Math.random()}`);
+      const userConfig = path.join(dir, 'config.json');
+      const systemConfig = path.join(dir, 'system.json');
+      mkdirSync(dir, { recursive: true });
+      writeFileSync(userConfig, JSON.stringify({ gateway: { port: 9100 } }, null, 2));
+      writeFileSync(systemConfig, JSON.stringify(system, null, 2));
+
+      const { getDataPaths } = await import('../../../../lib/paths.js');
+      vi.mocked(getDataPaths).mockReturnValue({
+        configFile: userConfig,
+        systemConfigFile: systemConfig,
+        dataDir: dir,
+        workspaceDir: path.join(dir, 'workspace'),
+      });
+
+      const bus2 = new EventEmitterBus();
+      bus2.bootstrap(new ConfigService(bus2));
+      return { bus: bus2, userConfig };
+    }
+
+    it('merges the system layer beneath user config', async () => {
+      const { bus: bus2 } = await setupWithSystemLayer({
+        gateway: { host: '0.0.0.0', port: 9000 },
+        linkExpand: { enabled: false },
+      });
+
+      const result = await bus2.call('config.get', {});
+      expect(result.gateway.host).toBe('0.0.0.0');
+      expect(result.gateway.port).toBe(9100);
+      expect((result.linkExpand as Record<string, unknown>).enabled).toBe(false);
+    });
+
+    it('does not copy inherited system values into the user file on config.set', async () => {
+      const { bus: bus2, userConfig } = await setupWithSystemLayer({ webhooks: [] });
+
+      const before = await bus2.call('config.get', {});
+      await bus2.call('config.set', { ...before, gateway: { ...before.gateway, port: 9200 } });
+
+      const written = JSON.parse(readFileSync(userConfig, 'utf8'));
+      expect(written.gateway.port).toBe(9200);
+      expect(written).not.toHaveProperty('webhooks');
+    });
+
+    it('keeps inherited object keys out of the user file when a sibling leaf changes', async () => {
+      const { bus: bus2, userConfig } = await setupWithSystemLayer({
+        gateway: { host: '0.0.0.0' },
+        linkExpand: { enabled: false },
+      });
+
+      const before = await bus2.call('config.get', {});
+      await bus2.call('config.set', { ...before, gateway: { ...before.gateway, port: 9200 } });
+
+      const written = JSON.parse(readFileSync(userConfig, 'utf8'));
+      expect(written.gateway).toEqual({ port: 9200 });
+      expect(written).not.toHaveProperty('linkExpand');
+
+      // A later admin change still reaches this user
+      const systemConfig = path.join(path.dirname(userConfig), 'system.json');
+      writeFileSync(systemConfig, JSON.stringify({ gateway: { host: '10.0.0.1' } }));
+      const after = await bus2.call('config.get', {});
+      expect(after.gateway.host).toBe('10.0.0.1');
+      expect(after.gateway.port).toBe(9200);
+    });
+
+    it('does not copy inherited agent settings or credentials into agent/*.json', async () => {
+      const { bus: bus2, userConfig } = await setupWithSystemLayer({
+        agent: { defaultModel: 'anthropic:admin-default' },
+        auth: { anthropic: { type: 'api_key', key: 'admin-key' } },
+      });
+      const agentDir = path.join(path.dirname(userConfig), 'agent');
+      mkdirSync(agentDir, { recursive: true });
+      writeFileSync(path.join(agentDir, 'settings.json'), JSON.stringify({ defaultThinkingLevel: 'low' }));
+
+      const before = await bus2.call('config.get', {});
+      await bus2.call('config.set', {
+        ...before,
+        gateway: { ...before.gateway, port: 9200 },
+        agent: { ...before.agent, defaultThinkingLevel: 'high' },
+      });
+
+      expect(JSON.parse(readFileSync(path.join(agentDir, 'settings.json'), 'utf8')))
+        .toEqual({ defaultThinkingLevel: 'high' });
+      expect(existsSync(path.join(agentDir, 'auth.json'))).toBe(false);
+
+      // A later admin change still reaches this user
+      const systemConfig = path.join(path.dirname(userConfig), 'system.json');
+      writeFileSync(systemConfig, JSON.stringify({ agent: { defaultModel: 'anthropic:new-default' } }));
+      const after = await bus2.call('config.get', {});
+      expect((after.agent as Record<string, unknown>).defaultModel).toBe('anthropic:new-default');
+      expect(after.agent?.defaultThinkingLevel).toBe('high');
+    });
+
+    it('names the system config file when one of its values is invalid', async () => {
+      const { bus: bus2 } = await setupWithSystemLayer({ heartbeat: { intervalMinutes: -5 } });
+      await expect(bus2.call('config.get', {})).rejects.toThrow(/Invalid system config at .*system\.json/);
+    });
+  });
+
+  describe('admin policy', () => {
+    async function setupWithPolicy(policy: Record<string, unknown>) {
+      const dir = path.join(os.tmpdir(), `config-test-policy-${Date.now()}-${Math.random()}`);
+      const userConfig = path.join(dir, 'config.json');
+      const policyFile = path.join(dir, 'policy.json');
+      mkdirSync(dir, { recursive: true });
+      writeFileSync(userConfig, JSON.stringify({ gateway: { host: '0.0.0.0', port: 9100 } }, null, 2));
+      writeFileSync(policyFile, JSON.stringify(policy, null, 2));
+
+      const { getDataPaths } = await import('../../../../lib/paths.js');
+      vi.mocked(getDataPaths).mockReturnValue({
+        configFile: userConfig,
+        policyFile,
+        dataDir: dir,
+        workspaceDir: path.join(dir, 'workspace'),
+      });
+
+      const bus2 = new EventEmitterBus();
+      bus2.bootstrap(new ConfigService(bus2));
+      return bus2;
+    }
+
+    it('overrides user config with policy values', async () => {
+      const bus2 = await setupWithPolicy({ gateway: { host: '127.0.0.1' } });
+
+      const result = await bus2.call('config.get', {});
+      expect(result.gateway.host).toBe('127.0.0.1');
+      expect(result.gateway.port).toBe(9100);
+    });
+
+    it('rejects config.set that changes a locked value', async () => {
+      const bus2 = await setupWithPolicy({ gateway: { host: '127.0.0.1' } });
+
+      const before = await bus2.call('config.get', {});
+      await expect(bus2.call('config.set', { ...before, gateway: { ...before.gateway, host: '0.0.0.0' } }))
+        .rejects.toThrow(/locked by admin policy/);
+      await expect(bus2.call('config.set', { ...before, gateway: { ...before.gateway, port: 9200 } }))
+        .resolves.toBeDefined();
+    });
+
+    it('fails to load when the policy file is not a JSON object', async () => {
+      const bus2 = await setupWithPolicy([] as unknown as Record<string, unknown>);
+      await expect(bus2.call('config.get', {})).rejects.toThrow(/Invalid policy/);
+    });
+
+    it('names the policy file when a policy value is invalid', async () => {
+      const bus2 = await setupWithPolicy({ gateway: { port: 'not-a-port' } });
+      await expect(bus2.call('config.get', {})).rejects.toThrow(/Invalid policy at .*policy\.json:\n {2}gateway\.port/);
+    });
+
+    it('rejects agent, providers and auth in the policy — the agent never reads them from it', async () => {
+      const bus2 = await setupWithPolicy({ agent: { defaultThinkingLevel: 'off' } });
+      await expect(bus2.call('config.get', {})).rejects.toThrow(/Invalid policy at .*policy\.json:\n {2}agent: cannot be enforced/);
+    });
+
+    it('rejects unknown top-level keys in the policy', async () => {
+      const bus2 = await setupWithPolicy({ linkExpnd: { enabled: false } });
+      await expect(bus2.call('config.get', {})).rejects.toThrow(/Invalid policy at .*policy\.json[\s\S]*linkExpnd/);
+    });
+  });
 });
@@ -1,21 +1,14 @@
 import { z } from 'zod';
 import { readFileSync, writeFileSync, mkdirSync, existsSync } from 'node:fs';
 import path from 'node:path';
+import { isDeepStrictEqual } from 'node:util';
 import { register } from '../../gateway/decorators.js';
 import type { Bus } from '../../gateway/bus.js';
 import type { EventMap } from '../../gateway/events.js';
 import {
-  AgentConfigSchema,
-  AuthSchema,
-  ChannelEntrySchema,
-  CronTaskSchema,
-  WebhookEntrySchema,
-  HeartbeatConfigSchema,
-  LinkExpandConfigSchema,
-  ProvidersSchema,
-  McpClientConfigSchema,
-  McpServerConfigSchema,
-  StorageConfigSchema,
+  AppConfigSchema,
+  PolicyLayerSchema,
+  type AppConfig,
   type ChannelEntry,
   type TelegramChannel,
   type WhatsAppChannel,
@@ -27,6 +20,7 @@ import {
   type HeartbeatConfig,
   type WebhookEntry,
   type LinkExpandConfig,
+  type HttpConfig,
   type McpClientConfig,
   type McpServerConfig,
   type StorageConfig,
@@ -35,43 +29,11 @@ import {
 } from './schemas/index.js';
 import { getDataPaths } from '../../lib/paths.js';
 import { createLogger } from '../../lib/logger.js';
+import { applyChanges, deepMerge, isPlainObject, type PlainObject } from '../../lib/merge.js';
+import { readAdminLayer, readLayer } from './layers.js';
 
-// ─── App config ───────────────────────────────────────────────────────────────
-
-export const AppConfigSchema = z
-  .object({
-    providers: ProvidersSchema.optional(),
-    agent: AgentConfigSchema.optional(),
-    auth: AuthSchema,
-    channels: z.array(ChannelEntrySchema).default([]),
-    cron: z.object({
-      tasks: z.array(CronTaskSchema).optional(),
-    }).optional(),
-    webhooks: z.array(WebhookEntrySchema).default([]),
-    heartbeat: HeartbeatConfigSchema.optional(),
-    linkExpand: LinkExpandConfigSchema.default({}),
-    mcp: McpClientConfigSchema.default({}),
-    mcpServers: z.record(z.string(), McpServerConfigSchema).optional().describe('External MCP servers to load as bus callable events'),
-    storage: StorageConfigSchema.optional(),
-    media: z.object({
-      audio: z.string().optional(),
-      image: z.string().optional(),
-    }).optional(),
-    paths: z.object({
-      dataDir: z.string().optional(),
-      workspace: z.string().optional(),
-    }).default({}),
-    gateway: z.object({
-      host: z.string().optional().default('127.0.0.1'),
-      port: z.number().int().min(1).max(65535).default(9000),
-      /** Client socket idle timeout (ms) for JSON-RPC connections */
-      requestTimeout: z.number().int().positive().optional(),
-    }).default({})
-  })
-  .passthrough();
-
-export type AppConfig = z.infer<typeof AppConfigSchema>;
 export type {
+  AppConfig,
   ChannelEntry,
   TelegramChannel,
   WhatsAppChannel,
@@ -84,6 +46,7 @@ export type {
   HeartbeatConfig,
   WebhookEntry,
   LinkExpandConfig,
+  HttpConfig,
   McpClientConfig,
   McpServerConfig,
   StorageConfig,
@@ -101,6 +64,8 @@ export function saveConfig(path: string, config: AppConfig): void {
 export class ConfigService {
   private readonly log = createLogger('config');
   private readonly configFile: string;
+  private readonly systemConfigFile: string | undefined;
+  private readonly policyFile: string | undefined;
   private readonly agentDir: string;
   private readonly agentModelsFile: string;
   private readonly agentSettingsFile: string;
@@ -109,16 +74,31 @@ export class ConfigService {
   constructor(
     private readonly bus: Bus
   ) {
-    const { configFile, dataDir } = getDataPaths();
+    const { configFile, dataDir, systemConfigFile, policyFile } = getDataPaths();
     this.configFile = configFile;
+    this.systemConfigFile = systemConfigFile;
+    this.policyFile = policyFile;
     this.agentDir = path.join(dataDir, 'agent');
     this.agentModelsFile = path.join(this.agentDir, 'models.json');
     this.agentSettingsFile = path.join(this.agentDir, 'settings.json');
     this.agentAuthFile = path.join(this.agentDir, 'auth.json');
   }
 
+  private loadSystemLayer(): PlainObject {
+    return readAdminLayer(this.systemConfigFile, 'system config');
+  }
+
+  private loadPolicy(): PlainObject {
+    return readAdminLayer(this.policyFile, 'policy', PolicyLayerSchema);
+  }
+
+  private readUserFile(): PlainObject {
+    return readLayer(this.configFile, 'config');
+  }
+
   private loadConfig(): AppConfig {
-    const raw = JSON.parse(readFileSync(this.configFile, 'utf8'));
+    // System layer sits beneath the user's config.json — user values win
+    const raw = deepMerge(this.loadSystemLayer(), this.readUserFile());
 
     // Load agent/settings.json and merge with existing agent config (settings takes precedence)
     try {
@@ -151,8 +131,13 @@ export class ConfigService {
       // File may not exist yet, that's okay
     }
 
+    // Policy is applied last — nothing the user writes can override it (it can't hold
+    // agent/providers/auth, so agent/*.json stays what the agent actually reads)
+    const policy = this.loadPolicy();
+    const enforced = deepMerge(raw, policy);
+
     // Validate merged config
-    const result = AppConfigSchema.safeParse(raw);
+    const result = AppConfigSchema.safeParse(enforced);
     if (!result.success) {
       const issues = result.error.issues
         .map(i => `  ${i.path.join('.')}: ${i.message}`)
@@ -177,66 +162,61 @@ export class ConfigService {
   async set(params: AppConfig): Promise<AppConfig> {
     const parsed = AppConfigSchema.parse(params);
 
-    // Split config into components by ownership
-    const configForFile: AppConfig = { ...parsed };
-    const agentModels: Record<string, unknown> = {};
-    let agentSettings: Record<string, unknown> = {};
-    let authData: Record<string, unknown> = {};
-
-    // Load existing agent/settings.json to preserve other fields
-    try {
-      agentSettings = JSON.parse(readFileSync(this.agentSettingsFile, 'utf8'));
-    } catch {
-      // File doesn't exist yet
-    }
-
-    // Extract agent config to agent/settings.json
-    if (configForFile.agent) {
-      agentSettings = { ...agentSettings, ...configForFile.agent };
-      // eslint-disable-next-line @typescript-eslint/no-explicit-any
-      delete (configForFile as any).agent;
-    }
-
-    // Load existing auth.json to preserve other credentials
+    // What the caller started from — only leaves that differ from it get written
+    let before: PlainObject = {};
     try {
-      authData = JSON.parse(readFileSync(this.agentAuthFile, 'utf8'));
+      before = this.loadConfig();
     } catch {
-      // File doesn't exist yet
-    }
-
-    // Extract auth credentials to agent/auth.json
-    if (configForFile.auth) {
-      authData = { ...authData, ...configForFile.auth };
-      // eslint-disable-next-line @typescript-eslint/no-explicit-any
-      delete (configForFile as any).auth;
+      // Current config doesn't load — write the caller's values in full
     }
 
-    // Extract providers to agent/models.json
-    if (configForFile.providers) {
-      agentModels.providers = configForFile.providers;
-      delete configForFile.providers;
-    }
-
-    // Load existing agent/models.json to preserve other fields
-    try {
-      const existing = JSON.parse(readFileSync(this.agentModelsFile, 'utf8'));
-      Object.assign(agentModels, existing, agentModels); // Preserve existing, override with new
-    } catch {
-      // File doesn't exist yet
+    const policy = this.loadPolicy();
+    const locked = Object.keys(policy).filter(key =>
+      !isDeepStrictEqual(deepMerge(parsed, { [key]: policy[key] })[key], (parsed as PlainObject)[key]),
+    );
+    if (locked.length > 0) {
+      throw new Error(`Config keys locked by admin policy (${this.policyFile}): ${locked.join(', ')}`);
     }
 
-    // Persist to appropriate files
-    saveConfig(this.configFile, configForFile);
-
+    // Every file gets only the leaves that changed against `before`, applied onto what is
+    // on disk — never the merged view, so system/policy values and schema defaults aren't
+    // pinned into the user's files and later admin changes still reach them
+    const readJsonFile = (file: string): PlainObject => {
+      try {
+        const data = JSON.parse(readFileSync(file, 'utf8'));
+        return isPlainObject(data) ? data : {};
+      } catch {
+        return {}; // File doesn't exist yet
+      }
+    };
+    const section = (obj: PlainObject, key: string): PlainObject =>
+      isPlainObject(obj[key]) ? obj[key] as PlainObject : {};
     const writeAgentFile = (file: string, data: Record<string, unknown>) => {
       if (Object.keys(data).length === 0) return;
       if (!existsSync(this.agentDir)) mkdirSync(this.agentDir, { recursive: true });
       writeFileSync(file, JSON.stringify(data, null, 2), { mode: 0o600 });
     };
 
-    writeAgentFile(this.agentModelsFile, agentModels);
-    writeAgentFile(this.agentSettingsFile, agentSettings);
-    writeAgentFile(this.agentAuthFile, authData);
+    // agent → agent/settings.json, auth → agent/auth.json, providers → agent/models.json
+    // (each left alone when the caller omits it)
+    if (parsed.agent) {
+      const settings = applyChanges(readJsonFile(this.agentSettingsFile), section(before, 'agent'), parsed.agent);
+      writeAgentFile(this.agentSettingsFile, settings);
+    }
+    if (parsed.auth) {
+      const auth = applyChanges(readJsonFile(this.agentAuthFile), section(before, 'auth'), parsed.auth);
+      writeAgentFile(this.agentAuthFile, auth);
+    }
+    if (parsed.providers) {
+      const models = readJsonFile(this.agentModelsFile);
+      const providers = applyChanges(section(models, 'providers'), section(before, 'providers'), parsed.providers);
+      writeAgentFile(this.agentModelsFile, { ...models, providers });
+    }
+
+    // Everything else → config.json (agent, auth and providers are dropped from it as before)
+    const strip = ({ agent: _agent, auth: _auth, providers: _providers, ...rest }: PlainObject) => rest;
+    const userFile = applyChanges(strip(this.readUserFile()), strip(before), strip(parsed));
+    saveConfig(this.configFile, userFile as AppConfig);
 
     this.log.info('config updated and persisted');
     return this.loadConfig();
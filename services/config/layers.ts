@@ -0,0 +1,74 @@
+/**
+ * Config layers — admin system config beneath the user's config.json, admin policy above it.
+ * Shared by ConfigService and the CLI so both resolve the same effective values.
+ *
+ * Exports:
+ *   readLayer()         → one JSON-object config file ({} when missing), naming the file on error
+ *   readAdminLayer()    → readLayer() + validation against AdminLayerSchema (or PolicyLayerSchema)
+ *   readConfigLayers()  → { system, user, policy } as they are on disk
+ *   mergeLayers()       → system ← user ← policy (raw: no agent/*.json, no schema defaults)
+ */
+
+import { existsSync, readFileSync } from 'node:fs';
+import { getDataPaths, type DataPaths } from '../../lib/paths.js';
+import { deepMerge, isPlainObject, type PlainObject } from '../../lib/merge.js';
+import { z } from 'zod';
+import { AdminLayerSchema, PolicyLayerSchema } from './schemas/app.js';
+
+export interface ConfigLayers {
+  system: PlainObject;
+  user: PlainObject;
+  policy: PlainObject;
+}
+
+type LayerPaths = Pick<DataPaths, 'configFile' | 'systemConfigFile' | 'policyFile'>;
+
+/**
+ * Read a config layer. Missing is fine; a present-but-unparseable file is an
+ * error naming that file, so a broken rollout or hand edit is noticed.
+ */
+export function readLayer(file: string | undefined, label: string): PlainObject {
+  if (!file || !existsSync(file)) return {};
+  let parsed: unknown;
+  try {
+    parsed = JSON.parse(readFileSync(file, 'utf8'));
+  } catch (err) {
+    throw new Error(`Invalid ${label} at ${file}: ${err instanceof Error ? err.message : err}`);
+  }
+  if (!isPlainObject(parsed)) {
+    throw new Error(`Invalid ${label} at ${file}: expected a JSON object`);
+  }
+  return parsed;
+}
+
+/**
+ * Read and validate an admin layer on its own, so a bad value is reported against
+ * the file that holds it rather than the user's config.json it gets merged into.
+ */
+export function readAdminLayer(
+  file: string | undefined,
+  label: string,
+  schema: z.ZodTypeAny = AdminLayerSchema,
+): PlainObject {
+  const layer = readLayer(file, label);
+  const result = schema.safeParse(layer);
+  if (!result.success) {
+    const issues = result.error.issues
+      .map(i => `  ${i.path.join('.') || '(root)'}: ${i.message}`)
+      .join('\n');
+    throw new Error(`Invalid ${label} at ${file}:\n${issues}`);
+  }
+  return layer;
+}
+
+export function readConfigLayers(paths: LayerPaths = getDataPaths()): ConfigLayers {
+  return {
+    system: readAdminLayer(paths.systemConfigFile, 'system config'),
+    user: readLayer(paths.configFile, 'config'),
+    policy: readAdminLayer(paths.policyFile, 'policy', PolicyLayerSchema),
+  };
+}
+
+export function mergeLayers({ system, user, policy }: ConfigLayers): PlainObject {
+  return deepMerge(deepMerge(system, user), policy);
+}
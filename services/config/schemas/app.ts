@@ -0,0 +1,72 @@
+/**
+ * Application config schema — the merged shape of config.json, agent/*.json,
+ * and the admin layers (system config beneath, policy above).
+ */
+
+import { z } from 'zod';
+import { AgentConfigSchema } from './agent.js';
+import { AuthSchema } from './auth.js';
+import { ChannelEntrySchema } from './channels.js';
+import { CronTaskSchema } from './cron.js';
+import { HeartbeatConfigSchema, HttpConfigSchema, LinkExpandConfigSchema } from './features.js';
+import { McpClientConfigSchema, McpServerConfigSchema } from './mcp.js';
+import { ProvidersSchema } from './providers.js';
+import { StorageConfigSchema } from './storage.js';
+import { WebhookEntrySchema } from './webhooks.js';
+
+export const AppConfigSchema = z
+  .object({
+    providers: ProvidersSchema.optional(),
+    agent: AgentConfigSchema.optional(),
+    auth: AuthSchema,
+    channels: z.array(ChannelEntrySchema).default([]),
+    cron: z.object({
+      tasks: z.array(CronTaskSchema).optional(),
+    }).optional(),
+    webhooks: z.array(WebhookEntrySchema).default([]),
+    heartbeat: HeartbeatConfigSchema.optional(),
+    linkExpand: LinkExpandConfigSchema.default({}),
+    http: HttpConfigSchema.default({}),
+    mcp: McpClientConfigSchema.default({}),
+    mcpServers: z.record(z.string(), McpServerConfigSchema).optional().describe('External MCP servers to load as bus callable events'),
+    storage: StorageConfigSchema.optional(),
+    media: z.object({
+      audio: z.string().optional(),
+      image: z.string().optional(),
+    }).optional(),
+    paths: z.object({
+      dataDir: z.string().optional(),
+      workspace: z.string().optional(),
+    }).default({}),
+    gateway: z.object({
+      host: z.string().optional().default('127.0.0.1'),
+      port: z.number().int().min(1).max(65535).default(9000),
+      /** Client socket idle timeout (ms) for JSON-RPC connections */
+      requestTimeout: z.number().int().positive().optional(),
+    }).default({})
+  })
+  .passthrough();
+
+/**
+ * One admin layer (/etc/vargos/config.json or policy.json) on its own: every key
+ * optional since it is merged with the others, but unknown top-level keys are
+ * rejected so a typo (`linkExpnd`) doesn't silently do nothing.
+ */
+export const AdminLayerSchema = AppConfigSchema.strict().deepPartial();
+
+/**
+ * The policy layer. `agent`, `providers` and `auth` are refused: the agent reads
+ * agent/*.json directly, so pinning them would show in config.get but change nothing.
+ */
+export const PolicyLayerSchema = AdminLayerSchema.superRefine((layer, ctx) => {
+  for (const key of ['agent', 'providers', 'auth'] as const) {
+    if (layer[key] === undefined) continue;
+    ctx.addIssue({
+      code: z.ZodIssueCode.custom,
+      path: [key],
+      message: 'cannot be enforced by policy — the agent reads agent/*.json directly',
+    });
+  }
+});
+
+export type AppConfig = z.infer<typeof AppConfigSchema>;
@@ -1,5 +1,5 @@
 /**
- * Feature-specific configuration schemas (Heartbeat, LinkExpand)
+ * Feature-specific configuration schemas (Heartbeat, LinkExpand, Http)
  */
 
 import { z } from 'zod';
@@ -21,5 +21,14 @@ export const LinkExpandConfigSchema = z.object({
   timeoutMs:     z.number().int().positive().default(5_000),
 });
 
+/** Identification sent on outbound requests vargos makes itself (web.fetch, link expansion) */
+export const HttpConfigSchema = z.object({
+  /** Overrides the default `vargos/<version> (<os>; node <version>)` */
+  userAgent:     z.string().optional(),
+  /** Send `X-Vargos-Client: <feature>/<version>` so operators can tell features apart */
+  clientHeader:  z.boolean().default(true),
+});
+
 export type HeartbeatConfig  = z.infer<typeof HeartbeatConfigSchema>;
 export type LinkExpandConfig = z.infer<typeof LinkExpandConfigSchema>;
+export type HttpConfig       = z.infer<typeof HttpConfigSchema>;
@@ -42,8 +42,8 @@ export {
 export type { PiAgentSettings, AgentConfig } from './agent.js';
 
 // Features
-export { HeartbeatConfigSchema, LinkExpandConfigSchema } from './features.js';
-export type { HeartbeatConfig, LinkExpandConfig } from './features.js';
+export { HeartbeatConfigSchema, LinkExpandConfigSchema, HttpConfigSchema } from './features.js';
+export type { HeartbeatConfig, LinkExpandConfig, HttpConfig } from './features.js';
 
 // MCP
 export { McpClientConfigSchema, McpServerConfigSchema } from './mcp.js';
@@ -52,3 +52,7 @@ export type { McpClientConfig, McpServerConfig } from './mcp.js';
 // Storage
 export { StorageConfigSchema } from './storage.js';
 export type { StorageConfig } from './storage.js';
+
+// App
+export { AppConfigSchema, AdminLayerSchema, PolicyLayerSchema } from './app.js';
+export type { AppConfig } from './app.js';
@@ -0,0 +1,74 @@
+import { describe, it, expect, beforeEach, afterEach, vi } from 'vitest';
+import { mkdirSync, readFileSync, rmSync } from 'node:fs';
+import path from 'node:path';
+import os from 'node:os';
+import { resetDataPaths } from '../../../lib/paths.js';
+import { LogService } from '../index.js';
+
+describe('LogService', () => {
+  let tmpDir: string;
+  const saved: Record<string, string | undefined> = {};
+
+  beforeEach(() => {
+    tmpDir = path.join(os.tmpdir(), `log-service-${process.pid}-${Date.now()}-${Math.random().toString(36).slice(2)}`);
+    mkdirSync(tmpDir, { recursive: true });
+    for (const k of ['VARGOS_DATA_DIR', 'LOG_LEVEL', 'LOG_FILE']) saved[k] = process.env[k];
+    process.env.VARGOS_DATA_DIR = tmpDir;
+    delete process.env.LOG_FILE;
+    resetDataPaths();
+    for (const method of ['debug', 'info', 'warn', 'error'] as const) {
+      vi.spyOn(console, method).mockImplementation(() => {});
+    }
+  });
+
+  afterEach(() => {
+    vi.restoreAllMocks();
+    for (const [k, v] of Object.entries(saved)) {
+      if (v === undefined) delete process.env[k];
+      else process.env[k] = v;
+    }
+    resetDataPaths();
+    rmSync(tmpDir, { recursive: true, force: true });
+  });
+
+  it('prints only levels that pass $LOG_LEVEL', () => {
+    process.env.LOG_LEVEL = 'warn';
+    const svc = new LogService();
+    svc.onLog({ level: 'info', service: 'test', message: 'quiet' });
+    svc.onLog({ level: 'warn', service: 'test', message: 'loud' });
+
+    expect(console.info).not.toHaveBeenCalled();
+    expect(console.warn).toHaveBeenCalledWith(expect.stringContaining('[test] loud'));
+  });
+
+  it('persists warn and error for log.search even below the threshold', async () => {
+    process.env.LOG_LEVEL = 'error';
+    const svc = new LogService();
+    svc.onLog({ level: 'warn', service: 'test', message: 'hidden but kept' });
+    svc.onLog({ level: 'info', service: 'test', message: 'dropped' });
+
+    expect(console.warn).not.toHaveBeenCalled();
+    await vi.waitFor(async () => {
+      expect((await svc.search({})).map(e => e.error)).toEqual(['hidden but kept']);
+    });
+  });
+
+  it('mirrors printed lines to $LOG_FILE', async () => {
+    process.env.LOG_LEVEL = 'info';
+    process.env.LOG_FILE = path.join(tmpDir, 'nested', 'vargos.log');
+    const svc = new LogService();
+    svc.onLog({ level: 'debug', service: 'test', message: 'below threshold' });
+    svc.onLog({ level: 'info', service: 'test', message: 'hello' });
+    await svc.close();
+
+    const lines = readFileSync(process.env.LOG_FILE, 'utf-8').trim().split('\n');
+    expect(lines).toHaveLength(1);
+    expect(lines[0]).toMatch(/INFO \[test\] hello$/);
+  });
+
+  it('keeps running when $LOG_FILE cannot be created', () => {
+    process.env.LOG_FILE = '/dev/null/vargos.log';
+    const svc = new LogService();
+    expect(() => svc.onLog({ level: 'info', service: 'test', message: 'still fine' })).not.toThrow();
+  });
+});
@@ -1,10 +1,10 @@
-import { promises as fs } from 'node:fs';
+import { createWriteStream, mkdirSync, promises as fs, type WriteStream } from 'node:fs';
 import path from 'node:path';
 import { z } from 'zod';
 import { on, register } from '../../gateway/decorators.js';
 import type { Bus } from '../../gateway/bus.js';
 import type { EventMap, LogLevel } from '../../gateway/events.js';
-import { setLoggerBus, ts } from '../../lib/logger.js';
+import { isLevelEnabled, setLoggerBus, ts } from '../../lib/logger.js';
 import { getDataPaths } from '../../lib/paths.js';
 
 interface LogEntry {
@@ -18,12 +18,40 @@ interface LogEntry {
 export class LogService {
   private logFile: string | null = null;
   private currentDate = '';
+  /** $LOG_FILE — every line that passes $LOG_LEVEL is also appended here */
+  private mirror: WriteStream | null = null;
+
+  constructor() {
+    const file = process.env.LOG_FILE?.trim();
+    if (!file) return;
+    // Never let logging take the service down — a bad path just means no mirror
+    try {
+      mkdirSync(path.dirname(file), { recursive: true });
+      this.mirror = createWriteStream(file, { flags: 'a' });
+      this.mirror.on('error', (err) => {
+        console.error(`Log file ${file} unavailable: ${err.message}`);
+        this.mirror = null;
+      });
+    } catch (err) {
+      console.error(`Log file ${file} unavailable: ${err instanceof Error ? err.message : err}`);
+    }
+  }
 
   @on('log.onLog')
   onLog(payload: EventMap['log.onLog']): void {
     const { level, service, message, data } = payload;
     const line = `${ts()} [${service}] ${message}${data ? ' ' + JSON.stringify(data) : ''}`;
 
+    // warn/error are always persisted for log.search, even when below the console threshold
+    if (!isLevelEnabled(level)) {
+      if (level === 'warn' || level === 'error') {
+        this.persist({ ts: new Date().toISOString(), level, service, message, data }).catch(() => { });
+      }
+      return;
+    }
+
+    this.mirror?.write(`${ts()} ${level.toUpperCase()} [${service}] ${message}${data ? ' ' + JSON.stringify(data) : ''}\n`);
+
     if (level === 'debug') {
       console.debug(line);
     }
@@ -78,6 +106,13 @@ export class LogService {
     return entries;
   }
 
+  /** Flush and close the $LOG_FILE mirror. */
+  close(): Promise<void> {
+    const mirror = this.mirror;
+    this.mirror = null;
+    return new Promise(resolve => (mirror ? mirror.end(resolve) : resolve()));
+  }
+
   private todayFile(): string {
     const date = new Date().toISOString().slice(0, 10);
     if (date !== this.currentDate) {
@@ -100,5 +135,5 @@ export async function boot(bus: Bus): Promise<{ stop?(): void }> {
   const svc = new LogService();
   bus.bootstrap(svc);
   setLoggerBus(bus);
-  return {};
+  return { stop: () => svc.close() };
 }
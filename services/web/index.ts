@@ -4,8 +4,12 @@ import type { Bus } from '../../gateway/bus.js';
 import type { EventMap } from '../../gateway/events.js';
 import { htmlToMarkdown } from '../../lib/html.js';
 import { validateHttpResponse } from '../../lib/http-validate.js';
+import { outboundHeaders } from '../../lib/user-agent.js';
+import type { HttpConfig } from '../config/index.js';
 
 export class WebService {
+  constructor(private readonly http: Partial<HttpConfig> = {}) { }
+
   @register('web.fetch', {
     description: 'Fetch a URL and return readable content (HTML → markdown).',
     schema: z.object({
@@ -23,7 +27,7 @@ export class WebService {
       throw new Error('Only http/https URLs are supported');
 
     const resp = await fetch(params.url, {
-      headers: { 'User-Agent': 'Mozilla/5.0 (compatible; Vargos/2.0)' },
+      headers: outboundHeaders('web-fetch', this.http),
       redirect: 'follow',
     });
 
@@ -55,6 +59,7 @@ function stripMarkdownLinks(md: string): string {
 // ── Boot ─────────────────────────────────────────────────────────────────────
 
 export async function boot(bus: Bus): Promise<{ stop?(): void }> {
-  bus.bootstrap(new WebService());
+  const config = await bus.call('config.get', {});
+  bus.bootstrap(new WebService(config.http));
   return {};
 }